@@ -0,0 +1,96 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::{fs, path::Path, process};
+
+use reqwest::Client;
+
+use crate::{request, Result};
+
+/// Read the exchange rates cached at the `path` if they exist. Otherwise, [`GET`](reqwest::get)
+/// the zipped file at the `url` with the `client`, unzip it, and [`write`] it to the `path`.
+pub async fn get_unzipped(client: &Client, url: &str, path: &Path) -> Result<String>
+{
+	match read(path)?
+	{
+		Some(contents) => Ok(contents),
+		None => write(path, request::get_unzipped_with(client, url).await?),
+	}
+}
+
+/// Like [`get_unzipped`], but blocks the current thread instead of being `async`.
+///
+/// # Panics
+///
+/// * When called from within an `async` runtime, because [`reqwest::blocking`] does.
+#[cfg(feature = "blocking")]
+pub fn get_unzipped_blocking(url: &str, path: &Path) -> Result<String>
+{
+	match read(path)?
+	{
+		Some(contents) => Ok(contents),
+		None => write(path, request::get_unzipped_blocking(url)?),
+	}
+}
+
+/// Read the exchange rates cached at the `path`, or [`None`] if there are none.
+pub fn read(path: &Path) -> Result<Option<String>>
+{
+	// TODO: use `try_exists` after rust-lang/rust#83186
+	if !path.exists()
+	{
+		return Ok(None);
+	}
+
+	#[cfg(feature = "observer")]
+	request::observe(|o| o.on_cache_hit(path));
+	Ok(Some(fs::read_to_string(path)?))
+}
+
+/// Cache the `contents` at the `path` for next time they are [`read`], returning them.
+///
+/// The `contents` are written to a temporary file which then replaces the `path`, so that a
+/// concurrent [`read`] (e.g. from another process) never sees them partially written.
+pub fn write(path: &Path, contents: String) -> Result<String>
+{
+	static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+	let mut temp = path.as_os_str().to_owned();
+	temp.push(format!(".{}-{}.tmp", process::id(), COUNT.fetch_add(1, Ordering::Relaxed)));
+	fs::write(&temp, &contents)?;
+
+	if let Err(e) = fs::rename(&temp, path)
+	{
+		fs::remove_file(&temp).ok();
+		return Err(e.into());
+	}
+
+	Ok(contents)
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::{env, fs, process};
+
+	use pretty_assertions::assert_eq;
+
+	use crate::SAMPLE_EXCHANGE_RATES_CSV;
+
+	#[test]
+	fn write()
+	{
+		let dir = env::temp_dir().join(format!("money2--write--{}", process::id()));
+		fs::create_dir_all(&dir).unwrap();
+
+		let path = dir.join("money2--test.csv");
+		assert_eq!(super::read(&path).unwrap(), None);
+
+		fs::write(&path, "outdated").unwrap();
+		let written = super::write(&path, SAMPLE_EXCHANGE_RATES_CSV.into()).unwrap();
+		assert_eq!(written, SAMPLE_EXCHANGE_RATES_CSV);
+		assert_eq!(super::read(&path).unwrap().as_deref(), Some(SAMPLE_EXCHANGE_RATES_CSV));
+
+		// the temporary file was renamed to the `path`
+		assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+		fs::remove_dir_all(dir).unwrap();
+	}
+}
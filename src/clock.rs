@@ -0,0 +1,25 @@
+use chrono::{Local, NaiveDate};
+
+/// A source of the current date, which is used to determine what "today" is for the
+/// [`HistoricalExchangeRates`](crate::HistoricalExchangeRates).
+///
+/// # See also
+///
+/// * [`HistoricalExchangeRates::set_clock`](crate::HistoricalExchangeRates::set_clock)
+pub trait Clock: Send + Sync
+{
+	/// Get the current date.
+	fn today(&self) -> NaiveDate;
+}
+
+/// A [`Clock`] which uses the [`Local`] time. This is the default [`Clock`].
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LocalClock;
+
+impl Clock for LocalClock
+{
+	fn today(&self) -> NaiveDate
+	{
+		Local::now().naive_local().date()
+	}
+}
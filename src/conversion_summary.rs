@@ -0,0 +1,20 @@
+use std::collections::HashMap;
+
+use crate::{Currency, Money};
+
+/// A summary of [exchanging](crate::Exchange::exchange) many [`Money`] into one [`Currency`].
+///
+/// # See also
+///
+/// * [`ExchangeRates::summarize`](crate::ExchangeRates::summarize), to create a
+///   [`ConversionSummary`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConversionSummary
+{
+	/// For each [`Currency`] that was exchanged from, the sum of the original [`Money`] in that
+	/// [`Currency`], and the sum of that [`Money`] after it was exchanged.
+	pub breakdown: HashMap<Currency, (Money, Money)>,
+
+	/// The sum of all the [`Money`] after it was exchanged.
+	pub total: Money,
+}
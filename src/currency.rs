@@ -2,6 +2,7 @@ mod display;
 mod from_str;
 mod try_from;
 
+use core::cmp::Ordering;
 use std::{collections::HashMap, sync::OnceLock};
 
 #[cfg(feature = "serde")]
@@ -9,11 +10,19 @@ use serde::{Deserialize, Serialize};
 use strum::{EnumCount, EnumIter, IntoEnumIterator, IntoStaticStr};
 use unicase::UniCase;
 
+pub(crate) use from_str::closest_code;
+
 /// [ISO-4217][iso] currency codes which are reported by the [European Central Bank][ecb] for
 /// exchange.
 ///
 /// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
 /// [iso]: https://www.iso.org/iso-4217-currency-codes.html
+///
+/// # Ordering
+///
+/// The derived [`Ord`] follows the order in which each variant is declared. That happens to be
+/// alphabetical by code, but is not guaranteed to remain so as currencies are added. Use
+/// [`Currency::cmp_by_code`] or [`Currency::sorted_by_code`] when the order must be alphabetical.
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize), serde(rename_all = "UPPERCASE"))]
 #[derive(
 	Copy,
@@ -132,6 +141,267 @@ pub enum Currency
 
 impl Currency
 {
+	/// Get every supported [`Currency`], in the order they are declared.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::Currency;
+	///
+	/// assert_eq!(Currency::all().next(), Some(Currency::Aud));
+	/// ```
+	pub fn all() -> impl Iterator<Item = Self>
+	{
+		Self::iter()
+	}
+
+	/// Get the code of every supported [`Currency`], in the same order as [`Currency::all`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::Currency;
+	///
+	/// assert_eq!(Currency::all_codes().take(3).collect::<Vec<_>>(), ["AUD", "BGN", "BRL"]);
+	/// ```
+	pub fn all_codes() -> impl Iterator<Item = &'static str>
+	{
+		Self::all().map(<&str>::from)
+	}
+
+	/// Compare `self` and the `other` [`Currency`] alphabetically by their codes.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use core::cmp::Ordering;
+	/// use money2::Currency;
+	///
+	/// assert_eq!(Currency::Usd.cmp_by_code(&Currency::Eur), Ordering::Greater);
+	/// ```
+	pub fn cmp_by_code(&self, other: &Self) -> Ordering
+	{
+		<&str>::from(self).cmp(<&str>::from(other))
+	}
+
+	/// Get the number of supported currencies, without having to import [`EnumCount`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use money2::{Currency, Decimal};
+	///
+	/// let rates: [Decimal; Currency::count()] = [Decimal::ONE; Currency::count()];
+	/// # assert_eq!(rates.len(), Currency::all().count());
+	/// ```
+	pub const fn count() -> usize
+	{
+		Self::COUNT
+	}
+
+	/// Attempts to convert a given ISO-4217 numeric `code` into a concrete [`Currency`], returning
+	/// [`Some`] if the operation succeeds, or [`None`] if not.
+	pub(crate) fn from_numeric_code(code: u16) -> Option<Self>
+	{
+		Self::iter().find(|currency| currency.numeric_code() == code)
+	}
+
+	/// Get the English name of this [`Currency`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::Currency;
+	///
+	/// assert_eq!(Currency::Usd.full_name(), "US dollar");
+	/// ```
+	pub const fn full_name(self) -> &'static str
+	{
+		match self
+		{
+			Self::Aud => "Australian dollar",
+			Self::Bgn => "Bulgarian lev",
+			Self::Brl => "Brazilian real",
+			Self::Cad => "Canadian dollar",
+			Self::Chf => "Swiss franc",
+			Self::Cny => "Chinese yuan",
+			Self::Czk => "Czech koruna",
+			Self::Dkk => "Danish krone",
+			Self::Eur => "Euro",
+			Self::Gbp => "British pound",
+			Self::Hkd => "Hong Kong dollar",
+			Self::Huf => "Hungarian forint",
+			Self::Idr => "Indonesian rupiah",
+			Self::Ils => "Israeli shekel",
+			Self::Inr => "Indian rupee",
+			Self::Isk => "Icelandic krona",
+			Self::Jpy => "Japanese yen",
+			Self::Krw => "South Korean won",
+			Self::Mxn => "Mexican peso",
+			Self::Myr => "Malaysian ringgit",
+			Self::Nok => "Norwegian krone",
+			Self::Nzd => "New Zealand dollar",
+			Self::Php => "Philippine peso",
+			Self::Pln => "Polish zloty",
+			Self::Ron => "Romanian leu",
+			Self::Rub => "Russian rouble",
+			Self::Sek => "Swedish krona",
+			Self::Sgd => "Singapore dollar",
+			Self::Thb => "Thai baht",
+			Self::Try => "Turkish lira",
+			Self::Usd => "US dollar",
+			Self::Zar => "South African rand",
+		}
+	}
+
+	/// Whether this [`Currency`] is the one which the [European Central Bank][ecb] quotes every
+	/// other [`Currency`] against (i.e. [`Currency::Eur`]).
+	///
+	/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+	///
+	/// Every exchange pivots through the base: an amount is first converted into [`Currency::Eur`],
+	/// and then from [`Currency::Eur`] into the desired [`Currency`]. See
+	/// [`ExchangeRates::explain`](crate::ExchangeRates::explain) for an example.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use money2::Currency;
+	///
+	/// assert!(Currency::Eur.is_base());
+	/// assert!(!Currency::Usd.is_base());
+	/// ```
+	pub const fn is_base(self) -> bool
+	{
+		matches!(self, Self::Eur)
+	}
+
+	/// Whether this [`Currency`] has no [minor units](Currency::minor_units), and is thus always
+	/// written without a decimal separator. Of the supported currencies, these are
+	/// [`Currency::Isk`], [`Currency::Jpy`], and [`Currency::Krw`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use money2::Currency;
+	///
+	/// assert!(Currency::Jpy.is_zero_decimal());
+	/// assert!(!Currency::Usd.is_zero_decimal());
+	/// ```
+	pub const fn is_zero_decimal(self) -> bool
+	{
+		self.minor_units() == 0
+	}
+
+	/// Get a label for this [`Currency`] which is suitable for a user interface, made from its
+	/// code, [name](Currency::full_name), and [symbol](Currency::symbol).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::Currency;
+	///
+	/// assert_eq!(Currency::Usd.label(), "USD — US dollar ($)");
+	/// ```
+	pub fn label(self) -> String
+	{
+		format!("{self} — {} ({})", self.full_name(), self.symbol())
+	}
+
+	/// Get the number of digits after the decimal separator which are used for this [`Currency`]'s
+	/// minor unit (e.g. `2` for cents of the US dollar), as defined by ISO-4217.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::Currency;
+	///
+	/// assert_eq!(Currency::Usd.minor_units(), 2);
+	/// assert_eq!(Currency::Jpy.minor_units(), 0);
+	/// ```
+	pub const fn minor_units(self) -> u32
+	{
+		match self
+		{
+			Self::Isk | Self::Jpy | Self::Krw => 0,
+			_ => 2,
+		}
+	}
+
+	/// Get the [minor units](Currency::minor_units) of every supported [`Currency`], in the same
+	/// order as [`Currency::all`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::Currency;
+	///
+	/// let table = Currency::minor_units_table();
+	/// assert!(table.contains(&(Currency::Jpy, 0)));
+	/// ```
+	pub fn minor_units_table() -> &'static [(Self, u32)]
+	{
+		static CELL: OnceLock<Vec<(Currency, u32)>> = OnceLock::new();
+		CELL.get_or_init(|| Self::all().map(|c| (c, c.minor_units())).collect())
+	}
+
+	/// Get the ISO-4217 numeric code of this [`Currency`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::Currency;
+	///
+	/// assert_eq!(Currency::Usd.numeric_code(), 840);
+	/// assert_eq!(Currency::try_from(840).unwrap(), Currency::Usd);
+	/// ```
+	pub const fn numeric_code(self) -> u16
+	{
+		match self
+		{
+			Self::Aud => 36,
+			Self::Bgn => 975,
+			Self::Brl => 986,
+			Self::Cad => 124,
+			Self::Chf => 756,
+			Self::Cny => 156,
+			Self::Czk => 203,
+			Self::Dkk => 208,
+			Self::Eur => 978,
+			Self::Gbp => 826,
+			Self::Hkd => 344,
+			Self::Huf => 348,
+			Self::Idr => 360,
+			Self::Ils => 376,
+			Self::Inr => 356,
+			Self::Isk => 352,
+			Self::Jpy => 392,
+			Self::Krw => 410,
+			Self::Mxn => 484,
+			Self::Myr => 458,
+			Self::Nok => 578,
+			Self::Nzd => 554,
+			Self::Php => 608,
+			Self::Pln => 985,
+			Self::Ron => 946,
+			Self::Rub => 643,
+			Self::Sek => 752,
+			Self::Sgd => 702,
+			Self::Thb => 764,
+			Self::Try => 949,
+			Self::Usd => 840,
+			Self::Zar => 710,
+		}
+	}
+
 	/// Attempts to convert a given string into a concrete [`Currency`], returning [`Some`] if the
 	/// operation succeeds, or [`None`] if not.
 	pub(crate) fn reverse_lookup(s: &str) -> Option<Self>
@@ -143,4 +413,149 @@ impl Currency
 		.get(&s.into())
 		.copied()
 	}
+
+	/// Get every supported [`Currency`], sorted alphabetically by code.
+	///
+	/// # See also
+	///
+	/// * [`Currency::cmp_by_code`]
+	pub fn sorted_by_code() -> Vec<Self>
+	{
+		let mut currencies: Vec<_> = Self::all().collect();
+		currencies.sort_unstable_by(Self::cmp_by_code);
+		currencies
+	}
+
+	/// Get the symbol which is used to represent this [`Currency`] in the English locale of the
+	/// [Unicode CLDR](https://cldr.unicode.org/). When a [`Currency`] has no distinct symbol, its
+	/// code is used instead.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::Currency;
+	///
+	/// assert_eq!(Currency::Usd.symbol(), "$");
+	/// assert_eq!(Currency::Cad.symbol(), "CA$");
+	/// assert_eq!(Currency::Chf.symbol(), "CHF");
+	/// ```
+	pub const fn symbol(self) -> &'static str
+	{
+		match self
+		{
+			Self::Aud => "A$",
+			Self::Bgn => "BGN",
+			Self::Brl => "R$",
+			Self::Cad => "CA$",
+			Self::Chf => "CHF",
+			Self::Cny => "CN¥",
+			Self::Czk => "CZK",
+			Self::Dkk => "DKK",
+			Self::Eur => "€",
+			Self::Gbp => "£",
+			Self::Hkd => "HK$",
+			Self::Huf => "HUF",
+			Self::Idr => "IDR",
+			Self::Ils => "₪",
+			Self::Inr => "₹",
+			Self::Isk => "ISK",
+			Self::Jpy => "¥",
+			Self::Krw => "₩",
+			Self::Mxn => "MX$",
+			Self::Myr => "MYR",
+			Self::Nok => "NOK",
+			Self::Nzd => "NZ$",
+			Self::Php => "₱",
+			Self::Pln => "PLN",
+			Self::Ron => "RON",
+			Self::Rub => "RUB",
+			Self::Sek => "SEK",
+			Self::Sgd => "SGD",
+			Self::Thb => "THB",
+			Self::Try => "TRY",
+			Self::Usd => "$",
+			Self::Zar => "ZAR",
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use core::cmp::Ordering;
+
+	use pretty_assertions::assert_eq;
+	use strum::EnumCount;
+
+	use super::Currency;
+
+	#[test]
+	fn all_codes()
+	{
+		assert_eq!(Currency::all().count(), Currency::COUNT);
+		assert_eq!(Currency::all_codes().count(), Currency::COUNT);
+		Currency::all().zip(Currency::all_codes()).for_each(|(currency, code)| {
+			assert_eq!(code.parse::<Currency>().unwrap(), currency);
+		});
+	}
+
+	#[test]
+	fn count()
+	{
+		assert_eq!(Currency::count(), Currency::COUNT);
+		assert_eq!(Currency::count(), Currency::all().count());
+	}
+
+	#[test]
+	fn is_base()
+	{
+		assert!(Currency::Eur.is_base());
+		assert_eq!(Currency::all().filter(|c| c.is_base()).collect::<Vec<_>>(), [Currency::Eur]);
+		assert!(Currency::default().is_base());
+	}
+
+	#[test]
+	fn is_zero_decimal()
+	{
+		assert!(Currency::Jpy.is_zero_decimal());
+		assert!(!Currency::Usd.is_zero_decimal());
+		Currency::all().for_each(|currency| {
+			assert_eq!(currency.is_zero_decimal(), currency.minor_units() == 0);
+		});
+	}
+
+	#[test]
+	fn label()
+	{
+		assert_eq!(Currency::Usd.label(), "USD — US dollar ($)");
+		assert_eq!(Currency::Eur.label(), "EUR — Euro (€)");
+		Currency::all().for_each(|currency| {
+			assert!(currency.label().starts_with(<&str>::from(currency)));
+			assert!(currency.label().contains(currency.full_name()));
+		});
+	}
+
+	#[test]
+	fn minor_units_table()
+	{
+		let table = Currency::minor_units_table();
+		assert_eq!(table.len(), Currency::COUNT);
+		assert!(table.contains(&(Currency::Jpy, 0)));
+		assert!(table.contains(&(Currency::Usd, 2)));
+		table.iter().for_each(|(currency, units)| assert_eq!(currency.minor_units(), *units));
+	}
+
+	#[test]
+	fn sorted_by_code()
+	{
+		let sorted = Currency::sorted_by_code();
+		assert_eq!(sorted.len(), Currency::COUNT);
+		assert_eq!(sorted.first(), Some(&Currency::Aud));
+		assert_eq!(sorted.last(), Some(&Currency::Zar));
+		sorted.windows(2).for_each(|pair| {
+			assert!(<&str>::from(pair[0]) < <&str>::from(pair[1]));
+			assert_eq!(pair[0].cmp_by_code(&pair[1]), Ordering::Less);
+		});
+	}
 }
@@ -1,14 +1,120 @@
 use core::str::FromStr;
 
+use strum::IntoEnumIterator;
+
 use super::Currency;
 use crate::{Error, Result};
 
+/// The greatest number of edits which a `code` may be from a supported [`Currency`]'s code for it
+/// to be [suggested](closest_code).
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// Get the supported [`Currency`] whose code is the fewest edits (case-insensitively) from the
+/// given `code`, for use in error messages (e.g. `USB` is close to [`Currency::Usd`]).
+///
+/// Returns [`None`] if the `code` is already supported, or there is no code within
+/// [`MAX_SUGGESTION_DISTANCE`] edits of it.
+pub fn closest_code(code: &str) -> Option<Currency>
+{
+	if Currency::reverse_lookup(code).is_some()
+	{
+		return None;
+	}
+
+	let code = code.to_ascii_uppercase();
+	Currency::iter()
+		.map(|currency| (currency, edit_distance(&code, currency.into())))
+		.filter(|(_, distance)| *distance <= MAX_SUGGESTION_DISTANCE)
+		.min_by_key(|(_, distance)| *distance)
+		.map(|(currency, _)| currency)
+}
+
+/// Get the [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance) between
+/// `lhs` and `rhs` (i.e. the fewest insertions, deletions, and substitutions of characters which
+/// turn one into the other).
+fn edit_distance(lhs: &str, rhs: &str) -> usize
+{
+	let rhs: Vec<_> = rhs.chars().collect();
+	let mut previous: Vec<_> = (0..=rhs.len()).collect();
+
+	lhs.chars().enumerate().for_each(|(i, l)| {
+		let mut current = vec![i + 1; rhs.len() + 1];
+		rhs.iter().enumerate().for_each(|(j, r)| {
+			let substitution = previous[j] + usize::from(l != *r);
+			current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+		});
+
+		previous = current;
+	});
+
+	previous[rhs.len()]
+}
+
 impl FromStr for Currency
 {
 	type Err = Error;
 
+	/// Parse a [`Currency`] from its code, case-insensitively.
+	///
+	/// When the code is not supported, the error message suggests the closest supported code (if
+	/// any).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::Currency;
+	///
+	/// assert_eq!("usd".parse::<Currency>().unwrap(), Currency::Usd);
+	/// assert!("USB".parse::<Currency>().unwrap_err().to_string().contains("did you mean USD?"));
+	/// ```
 	fn from_str(s: &str) -> Result<Self>
 	{
 		Self::reverse_lookup(s).ok_or_else(|| Error::UnsupportedCurrency(s.to_owned()))
 	}
 }
+
+#[cfg(test)]
+mod tests
+{
+	use pretty_assertions::assert_eq;
+
+	use super::Currency;
+	use crate::Error;
+
+	#[test]
+	fn closest_code()
+	{
+		assert_eq!(super::closest_code("USB"), Some(Currency::Usd));
+		assert_eq!(super::closest_code("us"), Some(Currency::Usd));
+		assert_eq!(super::closest_code("EURO"), Some(Currency::Eur));
+		assert_eq!(super::closest_code("USD"), None);
+		assert_eq!(super::closest_code("934"), None);
+		assert_eq!(super::closest_code("DOLLAR"), None);
+	}
+
+	#[test]
+	fn edit_distance()
+	{
+		assert_eq!(super::edit_distance("", ""), 0);
+		assert_eq!(super::edit_distance("USD", "USD"), 0);
+		assert_eq!(super::edit_distance("USB", "USD"), 1);
+		assert_eq!(super::edit_distance("US", "USD"), 1);
+		assert_eq!(super::edit_distance("kitten", "sitting"), 3);
+	}
+
+	#[test]
+	fn from_str()
+	{
+		assert_eq!("usd".parse::<Currency>().unwrap(), Currency::Usd);
+		assert_eq!("uSd".parse::<Currency>().unwrap(), Currency::Usd);
+
+		let error = "USB".parse::<Currency>().unwrap_err();
+		assert!(matches!(&error, Error::UnsupportedCurrency(c) if c == "USB"));
+		let message = error.to_string();
+		assert!(message.starts_with("The USB currency is not supported (did you mean USD?). See"));
+
+		let error = "DOLLAR".parse::<Currency>().unwrap_err();
+		assert!(error.to_string().starts_with("The DOLLAR currency is not supported. See"));
+	}
+}
@@ -1,6 +1,7 @@
 use core::str::FromStr;
 
 use super::Currency;
+use crate::Error;
 
 impl TryFrom<&str> for Currency
 {
@@ -11,3 +12,81 @@ impl TryFrom<&str> for Currency
 		s.parse()
 	}
 }
+
+impl TryFrom<&String> for Currency
+{
+	type Error = <Self as FromStr>::Err;
+
+	/// Same as converting from a [`&str`](str).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::Currency;
+	///
+	/// let s = String::from("USD");
+	/// assert_eq!(Currency::try_from(&s).unwrap(), Currency::Usd);
+	/// ```
+	fn try_from(s: &String) -> Result<Self, Self::Error>
+	{
+		Self::try_from(s.as_str())
+	}
+}
+
+impl TryFrom<String> for Currency
+{
+	type Error = <Self as FromStr>::Err;
+
+	/// Same as converting from a [`&str`](str).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::Currency;
+	///
+	/// let currency: Currency = String::from("USD").try_into().unwrap();
+	/// assert_eq!(currency, Currency::Usd);
+	/// ```
+	fn try_from(s: String) -> Result<Self, Self::Error>
+	{
+		Self::try_from(s.as_str())
+	}
+}
+
+impl TryFrom<u16> for Currency
+{
+	type Error = Error;
+
+	/// Convert an ISO-4217 numeric `code` into a [`Currency`].
+	fn try_from(code: u16) -> Result<Self, Self::Error>
+	{
+		Self::from_numeric_code(code).ok_or_else(|| Error::UnsupportedCurrency(code.to_string()))
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use pretty_assertions::assert_eq;
+	use strum::IntoEnumIterator;
+
+	use super::Currency;
+	use crate::Error;
+
+	#[test]
+	fn try_from_u16()
+	{
+		assert_eq!(Currency::try_from(840).unwrap(), Currency::Usd);
+		assert_eq!(Currency::try_from(36).unwrap(), Currency::Aud);
+		assert!(matches!(
+			Currency::try_from(934),
+			Err(Error::UnsupportedCurrency(c)) if c == "934"
+		));
+
+		Currency::iter().for_each(|currency| {
+			assert_eq!(Currency::try_from(currency.numeric_code()).unwrap(), currency);
+		});
+	}
+}
@@ -5,10 +5,27 @@ use std::io;
 
 use thiserror::Error;
 
+use crate::{currency, Currency, Decimal, Money};
+
 /// An [`Error`](std::error::Error) for the crate.
 #[derive(Debug, Error)]
 pub enum Error
 {
+	/// An operation which requires [`Money`](crate::Money) of the same [`Currency`] was given
+	/// [`Money`](crate::Money) of differing currencies.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use money2::{Currency, Error::CurrencyMismatch, Money};
+	///
+	/// let usd = Money::new(20_00, 2, Currency::Usd);
+	/// let eur = Money::new(20_00, 2, Currency::Eur);
+	/// assert!(matches!(usd.try_cmp(&eur), Err(CurrencyMismatch(Currency::Usd, Currency::Eur))));
+	/// ```
+	#[error("Expected the same currency, but got {0} and {1}")]
+	CurrencyMismatch(Currency, Currency),
+
 	/// The error was caused while performing operations on a [`Decimal`](crate::Decimal).
 	#[error(transparent)]
 	Decimal(#[from] rust_decimal::Error),
@@ -32,15 +49,47 @@ pub enum Error
 		reason: String,
 	},
 
+	/// The lower bound of a range of [`Money`] was greater than its upper bound.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use money2::{Currency, Error::InvalidBounds, Money};
+	///
+	/// let low = Money::new(10_00, 2, Currency::Usd);
+	/// let high = Money::new(5_00, 2, Currency::Usd);
+	/// assert!(matches!(low.try_clamp(low, high), Err(InvalidBounds(..))));
+	/// ```
+	#[error("Expected {0} to be less than or equal to {1}")]
+	InvalidBounds(Money, Money),
+
 	/// The error was caused while interacting with [`io`].
 	#[error(transparent)]
 	Io(#[from] io::Error),
 
+	/// A supported [`Currency`] had no rate in a given set of
+	/// [`ExchangeRates`](crate::ExchangeRates), so it could not be exchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use money2::{Currency, Error::MissingRate, ExchangeRates};
+	///
+	/// let rates: ExchangeRates = "Date, USD, \n03 June 2021, 1.2187, ".parse().unwrap();
+	/// assert!(matches!(
+	///   rates.try_index(&Currency::Usd..&Currency::Jpy),
+	///   Err(MissingRate(Currency::Jpy))
+	/// ));
+	/// ```
+	#[error("There is no rate of exchange for {0}")]
+	MissingRate(Currency),
+
 	/// The error was caused while [`reqwest`]ing exchange rates from upstream.
+	#[cfg(feature = "network")]
 	#[error(transparent)]
 	Reqwest(#[from] reqwest::Error),
 
-	/// A value had no equivalent [`Currency`](crate::Currency) variant.
+	/// A value had no equivalent [`Currency`] variant.
 	///
 	/// # Examples
 	///
@@ -48,17 +97,35 @@ pub enum Error
 	/// use money2::{Currency, Error::UnsupportedCurrency};
 	/// assert!(matches!(Currency::try_from("TMT"), Err(UnsupportedCurrency(_))));
 	/// ```
-	#[error("The {0} currency is not supported. See https://docs.rs/money2/latest/money2/type.Currency.html for a list of supported currencies")]
+	#[error("The {0} currency is not supported{}. See https://docs.rs/money2/latest/money2/type.Currency.html for a list of supported currencies", did_you_mean(.0))]
 	UnsupportedCurrency(String),
 
 	/// The error was caused while dealing with a downloaded [`zip`] file containing raw exchange
 	/// rates.
+	#[cfg(feature = "network")]
 	#[error(transparent)]
 	Zip(#[from] zip::result::ZipError),
 }
 
+/// Suggest the supported [`Currency`] which the unsupported `code` was most likely meant to be, if
+/// any.
+fn did_you_mean(code: &str) -> String
+{
+	currency::closest_code(code).map_or_else(String::new, |c| format!(" (did you mean {c}?)"))
+}
+
 impl Error
 {
+	/// Returns an error which indicates that a CSV had a `rate` for the `currency` which was zero
+	/// or negative, and thus could not be used to exchange [`Money`].
+	pub(crate) fn csv_rate_not_positive(currency: Currency, rate: Decimal) -> Self
+	{
+		Self::Decode {
+			context: "the exchange rates CSV from the ECB".into(),
+			reason:  format!("the rate of {currency} was {rate}, which is not positive"),
+		}
+	}
+
 	/// Returns an error which indicates that a CSV had a specific row missing which was expected to
 	/// be present.
 	pub(crate) fn csv_row_missing<D>(row: D) -> Self
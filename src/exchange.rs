@@ -20,6 +20,66 @@ pub trait Exchange
 	fn exchange_mut(&mut self, currency: Currency, rates: &ExchangeRates);
 }
 
+/// An extension to [`Iterator`]s of [`Exchange`]able items, which allows them to be
+/// [exchanged](Exchange::exchange) lazily.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// use money2::{Currency, ExchangeIteratorExt, ExchangeRates, Money};
+///
+/// let rates: ExchangeRates = "Date, USD, \n03 June 2021, 1.2187, ".parse().unwrap();
+/// let exchanged: Vec<_> =
+///   [Money::new(20_00, 2, Currency::Usd)].into_iter().exchange(Currency::Eur, &rates).collect();
+///
+/// assert_eq!(exchanged, [Money::new(16_41, 2, Currency::Eur)]);
+/// ```
+pub trait ExchangeIteratorExt: Iterator
+where
+	Self::Item: Exchange,
+{
+	/// Create an [`Iterator`] which [exchanges](Exchange::exchange) each item into another
+	/// `currency` using `rates`.
+	fn exchange(self, currency: Currency, rates: &ExchangeRates) -> impl Iterator<Item = Self::Item>
+	where
+		Self: Sized,
+	{
+		self.map(move |item| item.exchange(currency, rates))
+	}
+}
+
+impl<I> ExchangeIteratorExt for I
+where
+	I: Iterator,
+	I::Item: Exchange,
+{
+}
+
+impl<T, const N: usize> Exchange for [T; N]
+where
+	T: Exchange,
+{
+	fn exchange_mut(&mut self, currency: Currency, rates: &ExchangeRates)
+	{
+		self.as_mut_slice().exchange_mut(currency, rates);
+	}
+}
+
+impl<T, E> Exchange for Result<T, E>
+where
+	T: Exchange,
+{
+	/// [Exchange](Exchange::exchange_mut) the [`Ok`] value, leaving an [`Err`] as-is.
+	fn exchange_mut(&mut self, currency: Currency, rates: &ExchangeRates)
+	{
+		if let Ok(t) = self
+		{
+			t.exchange_mut(currency, rates);
+		}
+	}
+}
+
 impl<T> Exchange for [T]
 where
 	T: Exchange,
@@ -40,14 +100,35 @@ where
 	}
 }
 
+/// Implement [`Exchange`] for a tuple of [`Exchange`]able types, by exchanging each element.
+macro_rules! impl_exchange_for_tuple {
+	($($T:ident: $index:tt),+) => {
+		impl<$($T),+> Exchange for ($($T,)+)
+		where
+			$($T: Exchange,)+
+		{
+			fn exchange_mut(&mut self, currency: Currency, rates: &ExchangeRates)
+			{
+				$(self.$index.exchange_mut(currency, rates);)+
+			}
+		}
+	};
+}
+
+impl_exchange_for_tuple!(A: 0, B: 1);
+impl_exchange_for_tuple!(A: 0, B: 1, C: 2);
+impl_exchange_for_tuple!(A: 0, B: 1, C: 2, D: 3);
+
 #[cfg(test)]
 mod tests
 {
 	use pretty_assertions::assert_eq;
 
-	use crate::{Currency, Exchange, Money, SAMPLE_EXCHANGE_RATES_CSV};
+	use super::ExchangeIteratorExt;
+	use crate::{Currency, Exchange, ExchangeRates, Money, SAMPLE_EXCHANGE_RATES_CSV};
 
 	#[test]
+	#[allow(clippy::useless_conversion, reason = "mirrors the `into_iter` of `money`")]
 	fn exchange()
 	{
 		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse().unwrap();
@@ -63,4 +144,74 @@ mod tests
 			assert_eq!(lhs.currency, Currency::Eur);
 		});
 	}
+
+	#[test]
+	fn exchange_array()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+
+		let money = [
+			Money::new(1750, 0, Currency::Jpy),
+			Money::new(20_00, 2, Currency::Usd),
+			Money::new(5_00, 2, Currency::Eur),
+		];
+
+		assert_eq!(
+			money.exchange(Currency::Eur, &rates),
+			[
+				Money::new(13_08, 2, Currency::Eur),
+				Money::new(16_41, 2, Currency::Eur),
+				Money::new(5_00, 2, Currency::Eur),
+			]
+		);
+	}
+
+	#[test]
+	fn exchange_iterator()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+
+		let money = [Money::new(1750, 0, Currency::Jpy), Money::new(20_00, 2, Currency::Usd)];
+		let exchanged: Vec<_> = money.iter().copied().exchange(Currency::Eur, &rates).collect();
+
+		assert_eq!(exchanged, money.to_vec().exchange(Currency::Eur, &rates));
+		assert_eq!(
+			exchanged,
+			[Money::new(13_08, 2, Currency::Eur), Money::new(16_41, 2, Currency::Eur)]
+		);
+	}
+
+	#[test]
+	fn exchange_result()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+
+		let ok: Result<_, &str> = Ok(Money::new(20_00, 2, Currency::Usd));
+		assert_eq!(ok.exchange(Currency::Eur, &rates), Ok(Money::new(16_41, 2, Currency::Eur)));
+
+		let err: Result<Money, _> = Err("could not read the price");
+		assert_eq!(err.exchange(Currency::Eur, &rates), Err("could not read the price"));
+	}
+
+	#[test]
+	fn exchange_tuple()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+
+		let price = Money::new(20_00, 2, Currency::Usd);
+		let tax = Money::new(1750, 0, Currency::Jpy);
+
+		assert_eq!(
+			(price, tax).exchange(Currency::Eur, &rates),
+			(price.exchange(Currency::Eur, &rates), tax.exchange(Currency::Eur, &rates))
+		);
+		assert_eq!(
+			(price, tax, vec![price, tax]).exchange(Currency::Eur, &rates),
+			(
+				Money::new(16_41, 2, Currency::Eur),
+				Money::new(13_08, 2, Currency::Eur),
+				vec![Money::new(16_41, 2, Currency::Eur), Money::new(13_08, 2, Currency::Eur)],
+			)
+		);
+	}
 }
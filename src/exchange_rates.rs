@@ -1,41 +1,358 @@
+mod display;
+mod from_iterator;
 mod from_str;
 mod try_from;
 
-use core::ops::Range;
-use std::{collections::HashMap, env, fs, path::PathBuf};
+#[cfg(feature = "history")]
+pub(crate) use from_str::{has_trailing_comma, trim_csv_line};
 
-use chrono::{Datelike, Local};
+use core::{cmp::Ordering, ops::Range};
+#[cfg(feature = "network")]
+use core::time::Duration;
+use std::collections::HashMap;
+#[cfg(feature = "network")]
+use std::{
+	env,
+	fs,
+	path::PathBuf,
+	sync::{PoisonError, RwLock},
+};
 
-use crate::{request, Currency, Decimal, Result};
+use chrono::NaiveDate;
+#[cfg(feature = "network")]
+use chrono::{DateTime, Datelike, Local};
+
+#[cfg(feature = "network")]
+use crate::{cache, request};
+#[cfg(all(feature = "observer", feature = "network"))]
+use crate::RateFetchObserver;
+#[cfg(feature = "history")]
+use crate::HistoricalExchangeRates;
+use crate::{ConversionSummary, Currency, Decimal, Error, Exchange, Money, Result};
 
 /// A collection of rates of exchange between currencies such that some `amount` of
 /// [`Money`](crate::Money) divided by its [`Currency`] will yield [`Currency::Eur`], and an
 /// `amount` of [`Currency::Eur`] multiplied by any [`Currency`]'s exchange rate will yield that
 /// [`Currency`].
 ///
+/// In other words, [`Currency::Eur`] is the [base](Currency::is_base) which every exchange pivots
+/// through.
+///
 /// # See also
 ///
 /// * [`ExchangeRates::get`], to get the corresponding rate for some [`Currency`].
 /// * [`ExchangeRates::new`], to create new [`ExchangeRates`].
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ExchangeRates(pub(crate) HashMap<Currency, Decimal>);
+pub struct ExchangeRates
+{
+	/// The date which the `rates` are from, if it is known.
+	pub(crate) date: Option<NaiveDate>,
+
+	/// The currencies which had no rate on the `date` (e.g. `N/A` in an [ECB][ecb] CSV).
+	///
+	/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+	pub(crate) missing: Vec<Currency>,
+
+	/// The rate of exchange from [`Currency::Eur`] to each [`Currency`].
+	pub(crate) rates: HashMap<Currency, Decimal>,
+}
+
+/// The format of the date in an [ECB][ecb] CSV of the latest [`ExchangeRates`].
+///
+/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+const DATE_FORMAT: &str = "%d %B %Y";
+
+/// The directory which downloaded exchange rates are cached in, if not [`env::temp_dir`].
+#[cfg(feature = "network")]
+static CACHE_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
 
 impl ExchangeRates
 {
+	/// Get how long ago the [`ExchangeRates`] cached on the given `date` (or today, if [`None`])
+	/// were downloaded. This is useful to report how out of date the rates used by
+	/// [`ExchangeRates::new`] are.
+	///
+	/// Returns [`None`] if no [`ExchangeRates`] were cached on that `date`, or the age of the cache
+	/// cannot be determined.
+	#[cfg(feature = "network")]
+	pub fn cache_age(date: Option<DateTime<Local>>) -> Option<Duration>
+	{
+		let path = Self::filepath_on(date.unwrap_or_else(Local::now));
+		fs::metadata(path).and_then(|m| m.modified()).ok().and_then(|m| m.elapsed().ok())
+	}
+
+	/// Return the directory which downloaded exchange rates are cached in.
+	///
+	/// # See also
+	///
+	/// * [`ExchangeRates::set_cache_dir`]
+	#[cfg(feature = "network")]
+	pub(crate) fn cache_dir() -> PathBuf
+	{
+		let cache_dir = CACHE_DIR.read().unwrap_or_else(PoisonError::into_inner);
+		cache_dir.clone().unwrap_or_else(env::temp_dir)
+	}
+
+	/// Check that the [European Central Bank][ecb] can be reached, without downloading or parsing
+	/// any exchange rates (e.g. so that a service can fail fast at startup).
+	///
+	/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+	///
+	/// # Errors
+	///
+	/// * When the request fails, or the [ECB][ecb] responds with an error status.
+	#[cfg(feature = "network")]
+	pub async fn check_connectivity() -> Result<()>
+	{
+		request::head("https://www.ecb.europa.eu/stats/eurofxref/eurofxref.zip").await
+	}
+
+	/// Remove the [`RateFetchObserver`] set by [`ExchangeRates::set_observer`] (if any), so that it
+	/// is no longer notified.
+	#[cfg(all(feature = "observer", feature = "network"))]
+	pub fn clear_observer()
+	{
+		*request::OBSERVER.write().unwrap_or_else(PoisonError::into_inner) = None;
+	}
+
+	/// Compare the value of `a` and `b` after converting both into the `base` [`Currency`], so that
+	/// [`Money`] of different currencies can be sorted (e.g. with [`slice::sort_by`]).
+	///
+	/// Unlike [`Exchange::exchange`], the converted amounts are not rounded before they are
+	/// compared.
+	///
+	/// # Panics
+	///
+	/// * When [`ExchangeRates::index`] does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, ExchangeRates, Money};
+	///
+	/// let rates: ExchangeRates =
+	///   "Date, USD, JPY, \n03 June 2021, 1.2187, 133.81, ".parse().unwrap();
+	/// let mut values = [Money::new(20_00, 2, Currency::Usd), Money::new(1750, 0, Currency::Jpy)];
+	/// values.sort_by(|a, b| rates.compare(a, b, Currency::Eur));
+	///
+	/// assert_eq!(values, [
+	///   Money::new(1750, 0, Currency::Jpy),
+	///   Money::new(20_00, 2, Currency::Usd),
+	/// ]);
+	/// ```
+	pub fn compare(&self, a: &Money, b: &Money, base: Currency) -> Ordering
+	{
+		let value = |money: &Money| money.amount * self.index(&money.currency..&base);
+		value(a).cmp(&value(b))
+	}
+
+	/// Get the date which these [`ExchangeRates`] are from, if it is known (e.g. the business day
+	/// that the [ECB][ecb] published them).
+	///
+	/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use chrono::NaiveDate;
+	/// use money2::ExchangeRates;
+	///
+	/// let rates: ExchangeRates = "Date, USD, \n03 June 2021, 1.2187, ".parse().unwrap();
+	/// assert_eq!(rates.date(), NaiveDate::from_ymd_opt(2021, 6, 3));
+	/// ```
+	pub const fn date(&self) -> Option<NaiveDate>
+	{
+		self.date
+	}
+
+	/// Get how much the rate of each [`Currency`] from [`Currency::Eur`] changed between the
+	/// `other` [`ExchangeRates`] and these (i.e. the rate of these minus the rate of the `other`),
+	/// for every [`Currency`] which is in both (e.g. to detect a large swing between cached and
+	/// freshly-downloaded rates).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, ExchangeRates};
+	///
+	/// let old: ExchangeRates = "Date, USD, \n03 June 2021, 1.2187, ".parse().unwrap();
+	/// let new: ExchangeRates = "Date, USD, \n04 June 2021, 1.2163, ".parse().unwrap();
+	///
+	/// assert_eq!(new.diff(&old)[&Currency::Usd], Decimal::new(-24, 4));
+	/// ```
+	pub fn diff(&self, other: &Self) -> HashMap<Currency, Decimal>
+	{
+		self.rates
+			.iter()
+			.filter_map(|(currency, rate)| other.rates.get(currency).map(|o| (*currency, rate - o)))
+			.collect()
+	}
+
+	/// Show the arithmetic behind [`ExchangeRates::get`]: the rate from [`Currency::Eur`] to the
+	/// `from` [`Currency`], the rate from [`Currency::Eur`] to the `to` [`Currency`], and the rate
+	/// from `from` to `to` which results from dividing the latter by the former.
+	///
+	/// # Returns
+	///
+	/// * [`Some`] if this set of exchange rates accounts for both the `from` and `to` [`Currency`].
+	/// * [`None`] otherwise.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, ExchangeRates};
+	///
+	/// let rates: ExchangeRates =
+	///   "Date, USD, JPY, \n03 June 2021, 1.2187, 133.81, ".parse().unwrap();
+	/// let (from_rate, to_rate, rate) = rates.explain(Currency::Usd, Currency::Jpy).unwrap();
+	///
+	/// assert_eq!(from_rate, Decimal::new(1_2187, 4));
+	/// assert_eq!(to_rate, Decimal::new(133_81, 2));
+	/// assert_eq!(rate, to_rate / from_rate);
+	/// ```
+	pub fn explain(&self, from: Currency, to: Currency) -> Option<(Decimal, Decimal, Decimal)>
+	{
+		self.rates.get(&from).and_then(|f| self.rates.get(&to).map(|t| (*f, *t, t / f)))
+	}
+
 	/// Return the [filepath](PathBuf) which the latest [`ExchangeRates`] should be stored at.
 	///
 	/// There will be a new filepath each day.
+	#[cfg(feature = "network")]
 	fn filepath() -> PathBuf
 	{
-		let today = Local::now();
-		env::temp_dir().join(format!(
+		Self::filepath_on(Local::now())
+	}
+
+	/// Return the [filepath](PathBuf) which the latest [`ExchangeRates`] were stored at on the
+	/// given `date`.
+	#[cfg(feature = "network")]
+	fn filepath_on(date: DateTime<Local>) -> PathBuf
+	{
+		Self::cache_dir().join(format!(
 			"money2--{}-{}-{}.csv",
-			today.year(),
-			today.month(),
-			today.day()
+			date.year(),
+			date.month(),
+			date.day()
 		))
 	}
 
+	/// Like [`ExchangeRates::new`], but reads the [`ExchangeRates`] from the file at `path` if it
+	/// exists. Otherwise, the download is made with the `client` and written to `path`.
+	#[cfg(feature = "network")]
+	async fn from_ecb_cached_at(client: &reqwest::Client, path: PathBuf) -> Result<Self>
+	{
+		let url = "https://www.ecb.europa.eu/stats/eurofxref/eurofxref.zip";
+		cache::get_unzipped(client, url, &path).await?.parse()
+	}
+
+	/// Create a new [`ExchangeRates`] instance from the daily XML feed of the
+	/// [European Central Bank][ecb], rather than the zipped CSV used by [`ExchangeRates::new`].
+	/// This is useful when the download of zip files is blocked.
+	///
+	/// Unlike [`ExchangeRates::new`], the download is not cached.
+	///
+	/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+	///
+	/// # Errors
+	///
+	/// * When the download fails, or the XML cannot be [decoded](Error::Decode).
+	#[cfg(all(feature = "xml", feature = "network"))]
+	pub async fn from_ecb_xml() -> Result<Self>
+	{
+		let xml =
+			request::get_text("https://www.ecb.europa.eu/stats/eurofxref/eurofxref-daily.xml").await?;
+
+		Self::from_xml(&xml)
+	}
+
+	/// Parse [`ExchangeRates`] from a flat JSON object which maps each [`Currency`] code to its
+	/// rate of exchange from [`Currency::Eur`] (e.g. `{"USD": 1.2187, "JPY": 133.81}`).
+	///
+	/// The rate of [`Currency::Eur`] is always `1`, and the [`date`](ExchangeRates::date) is not
+	/// known.
+	///
+	/// # Errors
+	///
+	/// * [`Error::Decode`] if the `json` is not an object of numbers.
+	/// * [`Error::UnsupportedCurrency`] if the `json` contains an unknown [`Currency`] code.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, ExchangeRates};
+	///
+	/// let rates = ExchangeRates::from_json(r#"{"USD": 1.2187}"#).unwrap();
+	/// assert_eq!(rates.get(&Currency::Eur, &Currency::Usd), Some(Decimal::new(1_2187, 4)));
+	/// ```
+	#[cfg(feature = "serde")]
+	pub fn from_json(json: &str) -> Result<Self>
+	{
+		let object: HashMap<String, serde_json::Number> =
+			serde_json::from_str(json).map_err(|e| Error::Decode {
+				context: "exchange rates from JSON".into(),
+				reason:  e.to_string(),
+			})?;
+
+		let mut rates = HashMap::with_capacity(object.len() + 1);
+		rates.insert(Currency::Eur, Decimal::ONE);
+		object.into_iter().try_for_each(|(code, rate)| -> Result<()> {
+			rates.insert(code.parse()?, rate.to_string().parse()?);
+			Ok(())
+		})?;
+
+		Ok(Self { date: None, missing: Vec::new(), rates })
+	}
+
+	/// Parse [`ExchangeRates`] from the daily XML feed of the [ECB][ecb], which lists each rate as
+	/// `<Cube currency="USD" rate="1.2187"/>` within a `<Cube time="2021-06-03">`.
+	///
+	/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+	#[cfg(all(feature = "xml", feature = "network"))]
+	fn from_xml(xml: &str) -> Result<Self>
+	{
+		let new_error = |reason: String| -> Error {
+			Error::Decode { context: "the exchange rates XML from the ECB".into(), reason }
+		};
+
+		let document = roxmltree::Document::parse(xml).map_err(|e| new_error(e.to_string()))?;
+
+		let mut date = None;
+		let mut rates = HashMap::new();
+
+		// NOTE: conversion to EUR is not stored in ECB exchange rates, since the rates are given in
+		//       context of EUR to some other currency.
+		rates.insert(Currency::Eur, Decimal::ONE);
+		document
+			.descendants()
+			.filter(|node| node.tag_name().name() == "Cube")
+			.try_for_each(|cube| -> Result<()> {
+				if let Some(time) = cube.attribute("time")
+				{
+					date = NaiveDate::parse_from_str(time, "%Y-%m-%d").ok();
+				}
+
+				if let (Some(currency), Some(rate)) =
+					(cube.attribute("currency"), cube.attribute("rate"))
+				{
+					rates.insert(currency.parse()?, rate.parse()?);
+				}
+
+				Ok(())
+			})?;
+
+		match rates.len() > 1
+		{
+			false => Err(new_error("there were no exchange rates".into())),
+			_ => Ok(Self { date, missing: Vec::new(), rates }),
+		}
+	}
+
 	/// Retrieve a rate of exchange such that any [`Decimal`] in the `current` [`Currency`]
 	/// [multiplied by](std::ops::Mul) the return value will convert it to the `desired`
 	/// [`Currency`].
@@ -47,7 +364,37 @@ impl ExchangeRates
 	/// * [`None`] otherwise.
 	pub fn get(&self, current: &Currency, desired: &Currency) -> Option<Decimal>
 	{
-		self.0.get(current).and_then(|c| self.0.get(desired).map(|d| d / c))
+		self.explain(*current, *desired).map(|(.., rate)| rate)
+	}
+
+	/// Convert the `amount` into the `to` [`Currency`] using the [rate](ExchangeRates::get) between
+	/// them. The result will be [rounded](Decimal::rescale) to two decimal places.
+	///
+	/// # Returns
+	///
+	/// * [`Some`] if this set of exchange rates accounts for both the [`Currency`] of the `amount`
+	///   and the `to` [`Currency`].
+	/// * [`None`] otherwise.
+	///
+	/// # See also
+	///
+	/// * [`Money::try_exchange`], which returns an [`Err`] explaining why the conversion failed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, ExchangeRates, Money};
+	///
+	/// let rates: ExchangeRates = "Date, USD, \n03 June 2021, 1.2187, ".parse().unwrap();
+	/// let usd = Money::new(20_00, 2, Currency::Usd);
+	///
+	/// assert_eq!(rates.get_money(usd, Currency::Eur), Some(Money::new(16_41, 2, Currency::Eur)));
+	/// assert_eq!(rates.get_money(usd, Currency::Jpy), None);
+	/// ```
+	pub fn get_money(&self, amount: Money, to: Currency) -> Option<Money>
+	{
+		amount.try_exchange(to, self).ok()
 	}
 
 	/// Same as [`ExchangeRates::get`], except using range syntax (i.e. `current..desired`) and
@@ -58,9 +405,16 @@ impl ExchangeRates
 	/// * If any [`Currency`] in `range` is not present in this set of [`ExchangeRates`].
 	pub fn index(&self, range: Range<&Currency>) -> Decimal
 	{
-		self.get(range.start, range.end).unwrap_or_else(|| {
-			panic!("Either {} or {} was not found in {self:?}", range.start, range.end)
-		})
+		self.try_index(range).unwrap_or_else(|e| panic!("{e} in {self:?}"))
+	}
+
+	/// Get the currencies which were listed by the source of these [`ExchangeRates`], but had no
+	/// rate on their [`date`](ExchangeRates::date) (e.g. because a cell was `N/A` or empty).
+	///
+	/// [`ExchangeRates::get`] will return [`None`] for any of these currencies.
+	pub fn missing(&self) -> Vec<Currency>
+	{
+		self.missing.clone()
 	}
 
 	/// Create a new [`ExchangeRates`] instance, which uses the [European Central Bank][ecb] to
@@ -71,40 +425,483 @@ impl ExchangeRates
 	///       record is kept in-memory rather than on-disk.
 	///
 	/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+	#[cfg(feature = "network")]
 	pub async fn new() -> Result<Self>
 	{
-		match Self::filepath()
-		{
-			// PERF: `money2` caches ECB data until `Self::filepath()` changes
-			// TODO: use `try_exists` after rust-lang/rust#83186
-			path if path.exists() => fs::read_to_string(path)?,
-			path =>
-			{
-				let csv_contents = request::get_unzipped(
-					"https://www.ecb.europa.eu/stats/eurofxref/eurofxref.zip",
-				)
-				.await?;
-
-				// cache the download for next time this method is called
-				fs::write(path, &csv_contents)?;
-				csv_contents
+		// PERF: `money2` caches ECB data until `Self::filepath()` changes
+		Self::from_ecb_cached_at(&request::client()?, Self::filepath()).await
+	}
+
+	/// Like [`ExchangeRates::new`], but blocks the current thread instead of being `async`. The
+	/// same cache is shared by both functions.
+	///
+	/// # Panics
+	///
+	/// * When called from within an `async` runtime, because [`reqwest::blocking`] does.
+	#[cfg(feature = "blocking")]
+	pub fn new_blocking() -> Result<Self>
+	{
+		// PERF: `money2` caches ECB data until `Self::filepath()` changes
+		let url = "https://www.ecb.europa.eu/stats/eurofxref/eurofxref.zip";
+		cache::get_unzipped_blocking(url, &Self::filepath())?.parse()
+	}
+
+	/// Like [`ExchangeRates::new`], but downloads the [`ExchangeRates`] (if they are not already
+	/// cached) with the given `client` (e.g. one with custom TLS certificates, proxies, or
+	/// headers).
+	///
+	/// Unlike [`ExchangeRates::set_client`], the `client` is only used for this download.
+	#[cfg(feature = "network")]
+	pub async fn new_with_client(client: &reqwest::Client) -> Result<Self>
+	{
+		Self::from_ecb_cached_at(client, Self::filepath()).await
+	}
+
+	/// Parse each row of a CSV in the format of the [ECB][ecb]'s historical record (e.g.
+	/// `Date,USD,\n2021-06-03,1.2187,`) into the date of the row and its [`ExchangeRates`], sorted
+	/// from the earliest date to the latest.
+	///
+	/// This is a lighter alternative to
+	/// [`HistoricalExchangeRates::parse_csv`](crate::HistoricalExchangeRates::parse_csv) for when
+	/// the rates do not need to be looked up by date.
+	///
+	/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+	///
+	/// # Errors
+	///
+	/// * When [`HistoricalExchangeRates::parse_csv`](crate::HistoricalExchangeRates::parse_csv)
+	///   does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use chrono::NaiveDate;
+	/// use money2::{Currency, Decimal, ExchangeRates};
+	///
+	/// let csv = "Date,USD,\n2021-06-04,1.2163,\n2021-06-03,1.2187,\n";
+	/// let rates = ExchangeRates::parse_all(csv).unwrap();
+	///
+	/// assert_eq!(rates.len(), 2);
+	/// assert_eq!(rates[0].0, NaiveDate::from_ymd_opt(2021, 6, 3).unwrap());
+	/// assert_eq!(rates[0].1.get(&Currency::Eur, &Currency::Usd), Some(Decimal::new(1_2187, 4)));
+	/// ```
+	#[cfg(feature = "history")]
+	pub fn parse_all(csv: &str) -> Result<Vec<(NaiveDate, Self)>>
+	{
+		HistoricalExchangeRates::parse_csv(csv).map(|history| history.into_iter().collect())
+	}
+
+	/// Get how much the `amount` of the `base` [`Currency`] is worth in every [`Currency`] of these
+	/// [`ExchangeRates`] (including the `base`), sorted by [code](Currency::cmp_by_code). The
+	/// results are not rounded.
+	///
+	/// Returns an empty [`Vec`] if the `base` is not present in these [`ExchangeRates`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, ExchangeRates, Money};
+	///
+	/// let rates: ExchangeRates = "Date, USD, \n03 June 2021, 1.2187, ".parse().unwrap();
+	/// assert_eq!(rates.per_base(Currency::Eur, Decimal::TEN), [
+	///   Money::new(10, 0, Currency::Eur),
+	///   Money::new(12_1870, 4, Currency::Usd),
+	/// ]);
+	/// ```
+	pub fn per_base(&self, base: Currency, amount: Decimal) -> Vec<Money>
+	{
+		self.rates_by_code()
+			.into_iter()
+			.filter_map(|(currency, _)| {
+				self.get(&base, &currency).map(|rate| Money::from_decimal(amount * rate, currency))
+			})
+			.collect()
+	}
+
+	/// Look up the [rate](ExchangeRates::get) of exchange `from` one [`Currency`] `to` another
+	/// once, returning a function which converts any amount of the `from` [`Currency`] into the
+	/// `to` [`Currency`] without looking it up again (e.g. to exchange many amounts in a loop).
+	///
+	/// Like [`ExchangeRates::get`], the converted amount is not rounded.
+	///
+	/// # Returns
+	///
+	/// * [`Some`] if this set of exchange rates accounts for both the `from` and `to` [`Currency`].
+	/// * [`None`] otherwise.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, ExchangeRates};
+	///
+	/// let rates: ExchangeRates = "Date, USD, \n03 June 2021, 1.2187, ".parse().unwrap();
+	/// let eur_to_usd = rates.prepare(Currency::Eur, Currency::Usd).unwrap();
+	///
+	/// assert_eq!(eur_to_usd(Decimal::TEN), Decimal::new(12_1870, 4));
+	/// assert!(rates.prepare(Currency::Eur, Currency::Jpy).is_none());
+	/// ```
+	pub fn prepare(&self, from: Currency, to: Currency) -> Option<impl Fn(Decimal) -> Decimal>
+	{
+		self.get(&from, &to).map(|rate| move |amount| amount * rate)
+	}
+
+	/// Get the rate of each [`Currency`] in these [`ExchangeRates`], sorted by
+	/// [code](Currency::cmp_by_code). Anything which exports the rates should use this, so that its
+	/// output does not depend on the iteration order of the underlying [`HashMap`].
+	fn rates_by_code(&self) -> Vec<(Currency, Decimal)>
+	{
+		let mut rates: Vec<_> = self.rates.iter().map(|(c, rate)| (*c, *rate)).collect();
+		rates.sort_unstable_by(|(lhs, _), (rhs, _)| lhs.cmp_by_code(rhs));
+		rates
+	}
+
+	/// Whether the rates of this set of [`ExchangeRates`] are the same as the `other`'s,
+	/// regardless of the [`date`](ExchangeRates::date) of either.
+	pub fn rates_eq(&self, other: &Self) -> bool
+	{
+		self.rates == other.rates
+	}
+
+	/// Like [`ExchangeRates::new`], but always downloads the latest [`ExchangeRates`] from the
+	/// [ECB][ecb] (e.g. because they were updated since the cache was written). The cache is then
+	/// overwritten with the download.
+	///
+	/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+	#[cfg(feature = "network")]
+	pub async fn refresh() -> Result<Self>
+	{
+		let csv_contents =
+			request::get_unzipped("https://www.ecb.europa.eu/stats/eurofxref/eurofxref.zip").await?;
+
+		// cache the download for next time `ExchangeRates::new` is called
+		cache::write(&Self::filepath(), csv_contents)?.parse()
+	}
+
+	/// Like [`ExchangeRates::set_client`], but for the `blocking` functions (e.g.
+	/// [`ExchangeRates::new_blocking`]).
+	///
+	/// # Panics
+	///
+	/// * When called from within an `async` runtime while there is already a
+	///   [`reqwest::blocking::Client`] set, because dropping it does.
+	#[cfg(feature = "blocking")]
+	pub fn set_blocking_client(client: reqwest::blocking::Client)
+	{
+		*request::BLOCKING_CLIENT.write().unwrap_or_else(PoisonError::into_inner) = Some(client);
+	}
+
+	/// Set the `dir`ectory which downloaded exchange rates are cached in. The `dir` must already
+	/// exist.
+	///
+	/// By default, the [temporary directory](env::temp_dir) is used. However, that directory may be
+	/// shared with other users, or not persist between runs (e.g. in a container).
+	#[cfg(feature = "network")]
+	pub fn set_cache_dir(dir: PathBuf)
+	{
+		*CACHE_DIR.write().unwrap_or_else(PoisonError::into_inner) = Some(dir);
+	}
+
+	/// Set the [`reqwest::Client`] which is used to download exchange rates, replacing the previous
+	/// one (if any). This can be used to download through an authenticated
+	/// [proxy](reqwest::Proxy).
+	///
+	/// This also applies to downloads by the
+	/// [`HistoricalExchangeRates`](crate::HistoricalExchangeRates).
+	///
+	/// By default, a new [`reqwest::Client`] is used for each download.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use money2::ExchangeRates;
+	/// use reqwest::{Client, Proxy};
+	///
+	/// let proxy = Proxy::all("http://proxy.example.com:8080").unwrap().basic_auth("user", "pass");
+	/// ExchangeRates::set_client(Client::builder().proxy(proxy).build().unwrap());
+	/// ```
+	#[cfg(feature = "network")]
+	pub fn set_client(client: reqwest::Client)
+	{
+		*request::CLIENT.write().unwrap_or_else(PoisonError::into_inner) = Some(client);
+	}
+
+	/// Set the [`RateFetchObserver`] which is notified when [`ExchangeRates`] are read from the
+	/// cache or downloaded, replacing the previous one (if any).
+	///
+	/// This also applies to downloads by the
+	/// [`HistoricalExchangeRates`](crate::HistoricalExchangeRates).
+	///
+	/// # See also
+	///
+	/// * [`ExchangeRates::clear_observer`]
+	#[cfg(all(feature = "observer", feature = "network"))]
+	pub fn set_observer<O>(observer: O)
+	where
+		O: RateFetchObserver + 'static,
+	{
+		let mut lock = request::OBSERVER.write().unwrap_or_else(PoisonError::into_inner);
+		*lock = Some(Box::new(observer));
+	}
+
+	/// [Exchange](crate::Exchange::exchange) each of the `items` into the `to` [`Currency`], and
+	/// summarize the result both in total and for each [`Currency`] of the `items`.
+	///
+	/// # Panics
+	///
+	/// * When [`Exchange::exchange`](crate::Exchange::exchange) does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, ExchangeRates, Money};
+	///
+	/// let rates: ExchangeRates = "Date, USD, \n03 June 2021, 1.2187, ".parse().unwrap();
+	/// let summary = rates.summarize(
+	///   &[Money::new(20_00, 2, Currency::Usd), Money::new(5_00, 2, Currency::Eur)],
+	///   Currency::Eur,
+	/// );
+	///
+	/// assert_eq!(summary.total, Money::new(21_41, 2, Currency::Eur));
+	/// ```
+	pub fn summarize(&self, items: &[Money], to: Currency) -> ConversionSummary
+	{
+		items.iter().fold(
+			ConversionSummary { breakdown: HashMap::new(), total: Money::zero(to) },
+			|mut summary, item| {
+				let exchanged = item.exchange(to, self);
+				let (original_sum, exchanged_sum) = summary
+					.breakdown
+					.entry(item.currency)
+					.or_insert_with(|| (Money::zero(item.currency), Money::zero(to)));
+
+				*original_sum += *item;
+				*exchanged_sum += exchanged;
+				summary.total += exchanged;
+				summary
 			},
-		}
-		.parse()
+		)
+	}
+
+	/// Write these [`ExchangeRates`] as a table with a `CODE` and `RATE` column, sorted by code,
+	/// which is easier to read in logs than the [`Debug`] output.
+	///
+	/// # See also
+	///
+	/// * The [`Display`](core::fmt::Display) implementation, which writes a CSV instead.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::ExchangeRates;
+	///
+	/// let rates: ExchangeRates =
+	///   "Date, USD, JPY, \n03 June 2021, 1.2187, 133.81, ".parse().unwrap();
+	///
+	/// assert_eq!(rates.to_table(), "CODE  RATE\nEUR   1\nJPY   133.81\nUSD   1.2187\n");
+	/// ```
+	pub fn to_table(&self) -> String
+	{
+		// NOTE: every code is three letters, so only the header needs padding to be aligned
+		self.rates_by_code().into_iter().fold(String::from("CODE  RATE\n"), |mut table, (c, rate)| {
+			table.push_str(c.into());
+			table.push_str("   ");
+			table.push_str(&rate.to_string());
+			table.push('\n');
+			table
+		})
+	}
+
+	/// Same as [`ExchangeRates::index`], except returning an [`Err`] instead of panicking.
+	///
+	/// # Errors
+	///
+	/// * [`Error::MissingRate`] if either [`Currency`] in `range` is not present in this set of
+	///   [`ExchangeRates`].
+	pub fn try_index(&self, range: Range<&Currency>) -> Result<Decimal>
+	{
+		let rate = |currency: &Currency| {
+			self.rates.get(currency).ok_or(Error::MissingRate(*currency))
+		};
+
+		let current = rate(range.start)?;
+		rate(range.end).map(|desired| desired / current)
+	}
+
+	/// Get every supported [`Currency`] which has no rate in these [`ExchangeRates`], in the same
+	/// order as [`Currency::all`] (e.g. to check whether a custom set of rates is complete).
+	///
+	/// [`ExchangeRates::get`] will return [`None`] for any of these currencies.
+	///
+	/// # See also
+	///
+	/// * [`ExchangeRates::missing`], for the currencies which had no rate on a given date.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, ExchangeRates};
+	///
+	/// let rates: ExchangeRates = "Date, USD, \n03 June 2021, 1.2187, ".parse().unwrap();
+	/// let unsupported = rates.unsupported();
+	///
+	/// assert_eq!(unsupported.len(), Currency::all().count() - 2);
+	/// assert!(unsupported.contains(&Currency::Jpy));
+	/// assert!(!unsupported.contains(&Currency::Usd));
+	/// ```
+	pub fn unsupported(&self) -> Vec<Currency>
+	{
+		Currency::all().filter(|currency| !self.rates.contains_key(currency)).collect()
+	}
+
+	/// Check that this set of [`ExchangeRates`] is internally consistent, such that it can be used
+	/// to [`Exchange`](crate::Exchange) between currencies. This is useful to guard against bad
+	/// rates which were not retrieved from the [ECB][ecb] (e.g. from [`str::parse`]).
+	///
+	/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+	///
+	/// # Errors
+	///
+	/// * [`Error::Decode`] describing the first problem found, if:
+	///   * the rate of [`Currency::Eur`] is missing or not `1`, or
+	///   * any rate is zero or negative.
+	pub fn validate(&self) -> Result<()>
+	{
+		let new_error = |reason: String| -> Error {
+			Error::Decode { context: "the exchange rates".into(), reason }
+		};
+
+		match self.rates.get(&Currency::Eur)
+		{
+			Some(rate) if *rate == Decimal::ONE => Ok(()),
+			Some(rate) => Err(new_error(format!("the rate of EUR was {rate}, not 1"))),
+			None => Err(new_error("there was no rate for EUR".into())),
+		}?;
+
+		Currency::all()
+			.filter_map(|currency| self.rates.get(&currency).map(|rate| (currency, rate)))
+			.find(|(_, rate)| rate.is_sign_negative() || rate.is_zero())
+			.map_or(Ok(()), |(currency, rate)| {
+				Err(new_error(format!("the rate of {currency} was {rate}, which is not positive")))
+			})
 	}
 }
 
 #[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping, reason = "digits are grouped by decimal place")]
 mod tests
 {
-	use std::fs;
+	use core::cmp::Ordering;
+	#[cfg(feature = "network")]
+	use core::time::Duration;
+	use std::collections::HashMap;
+	#[cfg(all(feature = "observer", feature = "network"))]
+	use std::{
+		path::Path,
+		sync::{Arc, Mutex},
+	};
+	#[cfg(feature = "network")]
+	use std::{env, fs};
+
+	#[cfg(feature = "history")]
+	use chrono::Datelike;
+	#[cfg(feature = "network")]
+	use chrono::{Local, NaiveDate};
+	use pretty_assertions::assert_eq;
 
 	use super::ExchangeRates;
+	#[cfg(feature = "network")]
+	use crate::request;
+	#[cfg(feature = "serde")]
+	use crate::Exchange;
+	#[cfg(all(feature = "observer", feature = "network"))]
+	use crate::RateFetchObserver;
+	use crate::{Currency, Decimal, Error, Money, SAMPLE_EXCHANGE_RATES_CSV};
+	#[cfg(feature = "history")]
+	use crate::SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV;
+
+	/// Clears the [`RateFetchObserver`] when dropped, so that a test which sets one does not leave
+	/// it set for the others (even when an assertion fails).
+	#[cfg(all(feature = "observer", feature = "network"))]
+	struct ClearObserverOnDrop;
+
+	#[cfg(all(feature = "observer", feature = "network"))]
+	impl Drop for ClearObserverOnDrop
+	{
+		fn drop(&mut self)
+		{
+			ExchangeRates::clear_observer();
+		}
+	}
+
+	/// A [`RateFetchObserver`] which records each event it is notified of.
+	#[cfg(all(feature = "observer", feature = "network"))]
+	struct RecordingObserver(Arc<Mutex<Vec<String>>>);
+
+	#[cfg(all(feature = "observer", feature = "network"))]
+	impl RateFetchObserver for RecordingObserver
+	{
+		fn on_cache_hit(&self, path: &Path)
+		{
+			self.0.lock().unwrap().push(format!("cache hit {}", path.display()));
+		}
+
+		fn on_download_complete(&self, bytes: usize, _: Duration)
+		{
+			self.0.lock().unwrap().push(format!("download complete {}", bytes > 0));
+		}
 
+		fn on_download_start(&self, url: &str)
+		{
+			self.0.lock().unwrap().push(format!("download start {url}"));
+		}
+	}
+
+	#[cfg(feature = "network")]
+	#[tokio::test]
+	async fn from_ecb_cached_at()
+	{
+		let path = env::temp_dir().join("money2--daily--test.csv");
+		fs::write(&path, SAMPLE_EXCHANGE_RATES_CSV).unwrap();
+
+		let client = reqwest::Client::new();
+		assert_eq!(
+			ExchangeRates::from_ecb_cached_at(&client, path.clone()).await.unwrap(),
+			SAMPLE_EXCHANGE_RATES_CSV.parse().unwrap()
+		);
+
+		fs::remove_file(&path).unwrap();
+
+		// NOTE: the mock proxy cannot tunnel to the ECB, so the download fails after reaching it
+		let (client, proxy) = request::mock_proxy(b"HTTP/1.1 403 Forbidden\r\n\r\n".to_vec());
+		assert!(ExchangeRates::from_ecb_cached_at(&client, path.clone()).await.is_err());
+		assert!(proxy.join().unwrap().starts_with("CONNECT www.ecb.europa.eu:443 "));
+		assert!(!path.exists());
+	}
+
+	#[cfg(feature = "network")]
 	#[tokio::test]
 	async fn new()
 	{
+		let cache_dir = env::temp_dir().join("money2");
+		fs::create_dir_all(&cache_dir).unwrap();
+		ExchangeRates::set_cache_dir(cache_dir.clone());
+
+		#[cfg(feature = "observer")]
+		let events = Arc::new(Mutex::new(Vec::new()));
+		#[cfg(feature = "observer")]
+		ExchangeRates::set_observer(RecordingObserver(Arc::clone(&events)));
+		#[cfg(feature = "observer")]
+		let observer = ClearObserverOnDrop;
+
 		let filepath = ExchangeRates::filepath();
+		assert_eq!(filepath.parent(), Some(cache_dir.as_path()));
+
 		if filepath.exists()
 		{
 			fs::remove_file(&filepath).unwrap();
@@ -117,5 +914,429 @@ mod tests
 		let cached = ExchangeRates::new().await.unwrap();
 		assert!(filepath.is_file());
 		assert_eq!(downloaded, cached);
+
+		// NOTE: other tests may download concurrently, so there may be other events
+		#[cfg(feature = "observer")]
+		{
+			{
+				let events = events.lock().unwrap();
+				[
+					"download start https://www.ecb.europa.eu/stats/eurofxref/eurofxref.zip".into(),
+					"download complete true".into(),
+					format!("cache hit {}", filepath.display()),
+				]
+				.iter()
+				.for_each(|event| assert!(events.contains(event), "{event} not in {events:?}"));
+			}
+
+			drop(observer);
+			let count = events.lock().unwrap().len();
+			ExchangeRates::new().await.unwrap();
+			assert_eq!(events.lock().unwrap().len(), count);
+		}
+
+		// NOTE: tested here rather than separately, since both tests would share the cache
+		fs::write(&filepath, SAMPLE_EXCHANGE_RATES_CSV).unwrap();
+		let refreshed = ExchangeRates::refresh().await.unwrap();
+		assert_ne!(fs::read_to_string(&filepath).unwrap(), SAMPLE_EXCHANGE_RATES_CSV);
+		assert_eq!(ExchangeRates::new().await.unwrap(), refreshed);
+	}
+
+	#[cfg(feature = "blocking")]
+	#[test]
+	fn new_blocking()
+	{
+		let rates = ExchangeRates::new_blocking().unwrap();
+		assert_eq!(rates.rates.get(&Currency::Eur), Some(&Decimal::ONE));
+		assert_eq!(ExchangeRates::new_blocking().unwrap(), rates);
+	}
+
+	#[cfg(feature = "network")]
+	#[test]
+	fn cache_age()
+	{
+		let cache_dir = env::temp_dir().join("money2");
+		fs::create_dir_all(&cache_dir).unwrap();
+		ExchangeRates::set_cache_dir(cache_dir);
+
+		let date = NaiveDate::from_ymd_opt(2000, 1, 1)
+			.and_then(|d| d.and_hms_opt(0, 0, 0))
+			.and_then(|dt| dt.and_local_timezone(Local).earliest());
+
+		let filepath = ExchangeRates::filepath_on(date.unwrap());
+		if filepath.exists()
+		{
+			fs::remove_file(&filepath).unwrap();
+		}
+
+		assert_eq!(ExchangeRates::cache_age(date), None);
+
+		fs::write(&filepath, SAMPLE_EXCHANGE_RATES_CSV).unwrap();
+		let age = ExchangeRates::cache_age(date).unwrap();
+		assert!(age < Duration::from_secs(60));
+
+		fs::remove_file(&filepath).unwrap();
+	}
+
+	#[cfg(feature = "network")]
+	#[tokio::test]
+	async fn check_connectivity()
+	{
+		ExchangeRates::check_connectivity().await.unwrap();
+	}
+
+	#[test]
+	fn compare()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		let mut values = vec![
+			Money::new(20_00, 2, Currency::Usd),
+			Money::new(1750, 0, Currency::Jpy),
+			Money::new(10_00, 2, Currency::Eur),
+			Money::new(15_00, 2, Currency::Usd),
+			Money::new(2000, 0, Currency::Jpy),
+		];
+
+		values.sort_by(|a, b| rates.compare(a, b, Currency::Eur));
+		assert_eq!(values, [
+			Money::new(10_00, 2, Currency::Eur),
+			Money::new(15_00, 2, Currency::Usd),
+			Money::new(1750, 0, Currency::Jpy),
+			Money::new(2000, 0, Currency::Jpy),
+			Money::new(20_00, 2, Currency::Usd),
+		]);
+
+		assert_eq!(
+			rates.compare(
+				&Money::new(20_00, 2, Currency::Usd),
+				&Money::new(20_00, 2, Currency::Usd),
+				Currency::Jpy
+			),
+			Ordering::Equal
+		);
+	}
+
+	#[test]
+	fn diff()
+	{
+		let old: ExchangeRates = [
+			(Currency::Usd, Decimal::new(1_2187, 4)),
+			(Currency::Jpy, Decimal::new(133_81, 2)),
+			(Currency::Gbp, Decimal::new(85955, 5)),
+		]
+		.into_iter()
+		.collect();
+		let new: ExchangeRates = [
+			(Currency::Usd, Decimal::new(1_2163, 4)),
+			(Currency::Jpy, Decimal::new(140_00, 2)),
+			(Currency::Chf, Decimal::new(1_0961, 4)),
+		]
+		.into_iter()
+		.collect();
+
+		assert_eq!(
+			new.diff(&old),
+			[
+				(Currency::Eur, Decimal::ZERO),
+				(Currency::Jpy, Decimal::new(6_19, 2)),
+				(Currency::Usd, Decimal::new(-24, 4)),
+			]
+			.into_iter()
+			.collect()
+		);
+		assert_eq!(old.diff(&new)[&Currency::Jpy], Decimal::new(-6_19, 2));
+		assert!(old.diff(&old).values().all(Decimal::is_zero));
+	}
+
+	#[test]
+	fn explain()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		let (from_rate, to_rate, rate) = rates.explain(Currency::Usd, Currency::Jpy).unwrap();
+
+		assert_eq!(from_rate, Decimal::new(1_2187, 4));
+		assert_eq!(to_rate, Decimal::new(133_81, 2));
+		assert_eq!(rate, Decimal::new(133_81, 2) / Decimal::new(1_2187, 4));
+		assert_eq!(rates.get(&Currency::Usd, &Currency::Jpy), Some(rate));
+
+		assert_eq!(
+			rates.explain(Currency::Eur, Currency::Eur),
+			Some((Decimal::ONE, Decimal::ONE, Decimal::ONE))
+		);
+
+		let mut rates = rates;
+		rates.rates.remove(&Currency::Usd);
+		assert_eq!(rates.explain(Currency::Usd, Currency::Jpy), None);
+		assert_eq!(rates.explain(Currency::Jpy, Currency::Usd), None);
+	}
+
+	#[test]
+	fn get_money()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		let usd = Money::new(20_00, 2, Currency::Usd);
+
+		assert_eq!(rates.get_money(usd, Currency::Eur), Some(Money::new(16_41, 2, Currency::Eur)));
+		assert_eq!(rates.get_money(usd, Currency::Usd), Some(usd));
+
+		let mut rates = rates;
+		rates.rates.remove(&Currency::Usd);
+		assert_eq!(rates.get_money(usd, Currency::Eur), None);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn from_json()
+	{
+		let rates = ExchangeRates::from_json(r#"{"USD": 1.2187, "JPY": 133.81}"#).unwrap();
+		assert_eq!(rates.date(), None);
+		assert_eq!(rates.rates.len(), 3);
+		assert_eq!(rates.get(&Currency::Eur, &Currency::Eur), Some(Decimal::ONE));
+
+		let usd = Money::new(20_00, 2, Currency::Usd);
+		assert_eq!(usd.exchange(Currency::Jpy, &rates), Money::new(2195_95, 2, Currency::Jpy));
+
+		assert!(matches!(
+			ExchangeRates::from_json(r#"{"TMT": 1.0}"#),
+			Err(Error::UnsupportedCurrency(c)) if c == "TMT"
+		));
+		assert!(matches!(
+			ExchangeRates::from_json(r#"["USD", 1.2187]"#),
+			Err(Error::Decode { .. })
+		));
+	}
+
+	#[cfg(all(feature = "xml", feature = "network"))]
+	#[test]
+	fn from_xml()
+	{
+		const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gesmes:Envelope xmlns:gesmes="http://www.gesmes.org/xml/2002-08-01" xmlns="http://www.ecb.int/vocabulary/2002-08-01/eurofxref">
+	<gesmes:subject>Reference rates</gesmes:subject>
+	<gesmes:Sender>
+		<gesmes:name>European Central Bank</gesmes:name>
+	</gesmes:Sender>
+	<Cube>
+		<Cube time="2021-06-03">
+			<Cube currency="USD" rate="1.2187"/>
+			<Cube currency="JPY" rate="133.81"/>
+			<Cube currency="GBP" rate="0.85955"/>
+		</Cube>
+	</Cube>
+</gesmes:Envelope>"#;
+
+		let rates = ExchangeRates::from_xml(XML).unwrap();
+		assert_eq!(rates.date(), NaiveDate::from_ymd_opt(2021, 6, 3));
+		assert_eq!(rates.rates.len(), 4);
+
+		let csv = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		[Currency::Eur, Currency::Gbp, Currency::Jpy, Currency::Usd].into_iter().for_each(|c| {
+			assert_eq!(rates.get(&Currency::Eur, &c), csv.get(&Currency::Eur, &c));
+		});
+
+		assert!(matches!(ExchangeRates::from_xml("<Cube>"), Err(Error::Decode { .. })));
+		assert!(matches!(ExchangeRates::from_xml("<Cube/>"), Err(Error::Decode { .. })));
+	}
+
+	#[cfg(feature = "history")]
+	#[test]
+	fn parse_all()
+	{
+		let rates = ExchangeRates::parse_all(SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV).unwrap();
+		let dates: Vec<_> = rates.iter().map(|(date, _)| date.day()).collect();
+		assert_eq!(dates, [1, 2, 3, 4, 7]);
+
+		let (date, june_3) = &rates[2];
+		assert_eq!(june_3.date(), Some(*date));
+		assert_eq!(june_3.get(&Currency::Eur, &Currency::Usd), Some(Decimal::new(1_2187, 4)));
+		assert_eq!(june_3.missing(), []);
+
+		assert!(ExchangeRates::parse_all("Date,USD,\n").is_err());
+	}
+
+	#[test]
+	fn per_base()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		let per_eur = rates.per_base(Currency::Eur, Decimal::ONE);
+
+		assert_eq!(per_eur.len(), rates.rates.len());
+		per_eur.iter().for_each(|money| assert_eq!(money.amount, rates.rates[&money.currency]));
+		per_eur.windows(2).for_each(|pair| {
+			assert!(pair[0].currency.cmp_by_code(&pair[1].currency).is_lt());
+		});
+
+		let per_usd = rates.per_base(Currency::Usd, Decimal::ONE_HUNDRED);
+		assert!(per_usd.contains(&Money::new(100, 0, Currency::Usd)));
+
+		let mut rates = rates;
+		rates.rates.remove(&Currency::Usd);
+		assert_eq!(rates.per_base(Currency::Usd, Decimal::ONE), []);
+	}
+
+	#[test]
+	fn prepare()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		let usd_to_jpy = rates.prepare(Currency::Usd, Currency::Jpy).unwrap();
+		let rate = rates.get(&Currency::Usd, &Currency::Jpy).unwrap();
+
+		(-1_000..=1_000).map(|cents| Decimal::new(cents * 37, 2)).for_each(|amount| {
+			assert_eq!(usd_to_jpy(amount), amount * rate);
+		});
+
+		let mut rates = rates;
+		rates.rates.remove(&Currency::Usd);
+		assert!(rates.prepare(Currency::Usd, Currency::Jpy).is_none());
+		assert!(rates.prepare(Currency::Jpy, Currency::Usd).is_none());
+	}
+
+	#[test]
+	fn rates_eq()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+
+		let mut undated = rates.clone();
+		undated.date = None;
+		assert_ne!(rates, undated);
+		assert!(rates.rates_eq(&undated));
+
+		undated.rates.remove(&Currency::Usd);
+		assert!(!rates.rates_eq(&undated));
+	}
+
+	#[test]
+	fn summarize()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		let summary = rates.summarize(
+			&[
+				Money::new(20_00, 2, Currency::Usd),
+				Money::new(1750, 0, Currency::Jpy),
+				Money::new(10_00, 2, Currency::Usd),
+				Money::new(5_00, 2, Currency::Eur),
+			],
+			Currency::Eur,
+		);
+
+		assert_eq!(summary.total, Money::new(42_70, 2, Currency::Eur));
+		assert_eq!(summary.breakdown.len(), 3);
+		assert_eq!(
+			summary.breakdown[&Currency::Usd],
+			(Money::new(30_00, 2, Currency::Usd), Money::new(24_62, 2, Currency::Eur))
+		);
+		assert_eq!(
+			summary.breakdown[&Currency::Jpy],
+			(Money::new(1750, 0, Currency::Jpy), Money::new(13_08, 2, Currency::Eur))
+		);
+		assert_eq!(
+			summary.breakdown[&Currency::Eur],
+			(Money::new(5_00, 2, Currency::Eur), Money::new(5_00, 2, Currency::Eur))
+		);
+
+		let empty = rates.summarize(&[], Currency::Usd);
+		assert_eq!(empty.total, Money::new(0, 0, Currency::Usd));
+		assert!(empty.breakdown.is_empty());
+	}
+
+	#[test]
+	fn to_table()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		let table = rates.to_table();
+		let mut lines = table.lines();
+
+		assert_eq!(lines.next(), Some("CODE  RATE"));
+		let rows: Vec<_> = lines.map(|line| line.split_once("   ").unwrap()).collect();
+		assert_eq!(rows.len(), rates.rates.len());
+		assert!(rows.contains(&("EUR", "1")));
+		assert!(rows.contains(&("USD", "1.2187")));
+		assert!(rows.windows(2).all(|pair| pair[0].0 < pair[1].0));
+	}
+
+	#[test]
+	fn export_is_deterministic()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+
+		// NOTE: the same rates, inserted in the opposite order and into a map of another capacity
+		let mut reversed = rates.clone();
+		reversed.rates = HashMap::with_capacity(rates.rates.len() * 4);
+		rates.rates_by_code().into_iter().rev().for_each(|(c, rate)| {
+			reversed.rates.insert(c, rate);
+		});
+
+		assert_eq!(rates.to_string(), rates.to_string());
+		assert_eq!(rates.to_string(), reversed.to_string());
+		assert_eq!(rates.to_table(), rates.to_table());
+		assert_eq!(rates.to_table(), reversed.to_table());
+	}
+
+	#[test]
+	fn try_index()
+	{
+		let mut rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		assert_eq!(
+			rates.try_index(&Currency::Eur..&Currency::Usd).unwrap(),
+			Decimal::new(1_2187, 4)
+		);
+
+		rates.rates.remove(&Currency::Usd);
+		assert!(matches!(
+			rates.try_index(&Currency::Usd..&Currency::Eur),
+			Err(Error::MissingRate(Currency::Usd))
+		));
+		assert!(matches!(
+			rates.try_index(&Currency::Eur..&Currency::Usd),
+			Err(Error::MissingRate(Currency::Usd))
+		));
+		assert_eq!(
+			rates.try_index(&Currency::Eur..&Currency::Usd).unwrap_err().to_string(),
+			"There is no rate of exchange for USD"
+		);
+	}
+
+	#[test]
+	fn unsupported()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		assert_eq!(rates.unsupported(), []);
+
+		let partial: ExchangeRates = [
+			(Currency::Usd, Decimal::new(1_2187, 4)),
+			(Currency::Jpy, Decimal::new(133_81, 2)),
+		]
+		.into_iter()
+		.collect();
+		let unsupported = partial.unsupported();
+
+		assert_eq!(unsupported.len(), Currency::all().count() - 3);
+		assert_eq!(unsupported[..3], [Currency::Aud, Currency::Bgn, Currency::Brl]);
+		[Currency::Eur, Currency::Jpy, Currency::Usd].into_iter().for_each(|currency| {
+			assert!(!unsupported.contains(&currency));
+		});
+	}
+
+	#[test]
+	fn validate()
+	{
+		let mut rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		assert!(rates.validate().is_ok());
+
+		let mut zero = rates.clone();
+		zero.rates.insert(Currency::Usd, Decimal::ZERO);
+		assert!(matches!(
+			zero.validate(),
+			Err(Error::Decode { reason, .. }) if reason.contains("USD")
+		));
+
+		rates.rates.insert(Currency::Usd, Decimal::NEGATIVE_ONE);
+		assert!(matches!(rates.validate(), Err(Error::Decode { .. })));
+
+		rates.rates.remove(&Currency::Eur);
+		assert!(matches!(
+			rates.validate(),
+			Err(Error::Decode { reason, .. }) if reason.contains("EUR")
+		));
 	}
 }
@@ -0,0 +1,53 @@
+use core::fmt::{Display, Formatter, Result};
+
+use super::{ExchangeRates, DATE_FORMAT};
+use crate::Currency;
+
+impl Display for ExchangeRates
+{
+	/// Write the rates as a two-line CSV in the same format that the [ECB][ecb] uses, such that it
+	/// can be [parsed](core::str::FromStr) back into [`ExchangeRates`]. Currencies are sorted by
+	/// their code.
+	///
+	/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+	fn fmt(&self, f: &mut Formatter) -> Result
+	{
+		let rates: Vec<_> = self
+			.rates_by_code()
+			.into_iter()
+			.filter(|(currency, _)| *currency != Currency::Eur)
+			.map(|(currency, rate)| (<&str>::from(currency), rate))
+			.collect();
+
+		write!(f, "Date, ")?;
+		rates.iter().try_for_each(|(code, _)| write!(f, "{code}, "))?;
+
+		writeln!(f)?;
+		match self.date
+		{
+			Some(date) => write!(f, "{}, ", date.format(DATE_FORMAT))?,
+			None => write!(f, ", ")?,
+		};
+
+		rates.iter().try_for_each(|(_, rate)| write!(f, "{rate}, "))
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use pretty_assertions::assert_eq;
+
+	use super::ExchangeRates;
+	use crate::SAMPLE_EXCHANGE_RATES_CSV;
+
+	#[test]
+	fn fmt()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		let csv = rates.to_string();
+
+		assert!(csv.starts_with("Date, AUD, BGN, BRL, "));
+		assert_eq!(csv.parse::<ExchangeRates>().unwrap(), rates);
+	}
+}
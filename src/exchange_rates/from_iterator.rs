@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use super::ExchangeRates;
+use crate::{Currency, Decimal};
+
+impl FromIterator<(Currency, Decimal)> for ExchangeRates
+{
+	/// Collect the rate of exchange from [`Currency::Eur`] to each [`Currency`] into a set of
+	/// [`ExchangeRates`]. The rate of [`Currency::Eur`] is `1` unless otherwise given, and the
+	/// [`date`](ExchangeRates::date) is not known.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, ExchangeRates};
+	///
+	/// let rates: ExchangeRates = [(Currency::Usd, Decimal::new(1_2187, 4))].into_iter().collect();
+	/// assert_eq!(rates.get(&Currency::Eur, &Currency::Eur), Some(Decimal::ONE));
+	/// ```
+	fn from_iter<T>(iter: T) -> Self
+	where
+		T: IntoIterator<Item = (Currency, Decimal)>,
+	{
+		let mut rates: HashMap<_, _> = iter.into_iter().collect();
+		rates.entry(Currency::Eur).or_insert(Decimal::ONE);
+
+		Self { date: None, missing: Vec::new(), rates }
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping, reason = "digits are grouped by decimal place")]
+mod tests
+{
+	use pretty_assertions::assert_eq;
+
+	use super::ExchangeRates;
+	use crate::{Currency, Decimal, Exchange, Money};
+
+	#[test]
+	fn from_iter()
+	{
+		let rates: ExchangeRates = [
+			(Currency::Jpy, Decimal::new(133_81, 2)),
+			(Currency::Usd, Decimal::new(1_2187, 4)),
+		]
+		.into_iter()
+		.collect();
+
+		assert_eq!(rates.date(), None);
+		assert_eq!(rates.rates.len(), 3);
+		assert_eq!(
+			Money::new(20_00, 2, Currency::Usd).exchange(Currency::Jpy, &rates),
+			Money::new(2195_95, 2, Currency::Jpy)
+		);
+		assert_eq!(
+			Money::new(20_00, 2, Currency::Usd).exchange(Currency::Eur, &rates),
+			Money::new(16_41, 2, Currency::Eur)
+		);
+	}
+}
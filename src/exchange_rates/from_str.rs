@@ -1,11 +1,36 @@
 use core::str::FromStr;
 use std::collections::HashMap;
 
+use chrono::NaiveDate;
 use strum::EnumCount;
 
-use super::ExchangeRates;
+use super::{ExchangeRates, DATE_FORMAT};
 use crate::{Currency, Decimal, Error, Result};
 
+/// Whether the `header` line of a CSV ends with a comma, as those from the [ECB][ecb] do. When it
+/// does, every other line is expected to as well, so it should be passed to [`trim_csv_line`].
+///
+/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+pub fn has_trailing_comma(header: &str) -> bool
+{
+	header.trim_end().ends_with(',')
+}
+
+/// Remove any trailing whitespace from a `line` of a CSV, along with the `trailing_comma` (see
+/// [`has_trailing_comma`]). Otherwise, the last column of every line would be empty.
+///
+/// At most one comma is removed, and only when the CSV has a `trailing_comma`, so that an empty
+/// value in the last column is preserved.
+pub fn trim_csv_line(line: &str, trailing_comma: bool) -> &str
+{
+	let line = line.trim_end();
+	match trailing_comma
+	{
+		false => line,
+		_ => line.strip_suffix(',').unwrap_or(line),
+	}
+}
+
 impl FromStr for ExchangeRates
 {
 	type Err = Error;
@@ -13,47 +38,60 @@ impl FromStr for ExchangeRates
 	fn from_str(csv: &str) -> Result<Self>
 	{
 		// {{{
-		let mut rows_by_columns = csv.lines().map(|line| line.split(", "));
+		let trailing_comma = csv.lines().next().is_some_and(has_trailing_comma);
+		let mut rows_by_columns =
+			csv.lines().map(|line| trim_csv_line(line, trailing_comma).split(", "));
 
 		#[rustfmt::skip] let currencies = rows_by_columns.next().ok_or_else(|| Error::csv_row_missing("currency"))?;
-		#[rustfmt::skip] let rates = rows_by_columns.next().ok_or_else(|| Error::csv_row_missing("exchange rate"))?;
+		#[rustfmt::skip] let mut rates = rows_by_columns.next().ok_or_else(|| Error::csv_row_missing("exchange rate"))?;
 
 		drop(rows_by_columns);
 		// }}}
 
+		let date = rates.next().and_then(|d| NaiveDate::parse_from_str(d, DATE_FORMAT).ok());
 		let mut map = HashMap::with_capacity(Currency::COUNT);
 
 		// NOTE: conversion to EUR is not stored in ECB exchange rates, since the rates are given in
 		//       context of EUR to some other currency.
 		map.insert(Currency::Eur, 1.into());
 		currencies
-			.zip(rates)
 			.skip(1)
+			.zip(rates)
 			.filter(|(c, _)| !c.is_empty())
 			.try_for_each(|(c, r)| -> Result<()> {
 				let currency = c.parse::<Currency>()?;
-				map.insert(currency, r.parse::<Decimal>()?);
+				let rate = r.parse::<Decimal>()?;
+				if rate.is_sign_negative() || rate.is_zero()
+				{
+					return Err(Error::csv_rate_not_positive(currency, rate));
+				}
+
+				map.insert(currency, rate);
 				Ok(())
 			})
-			.and(Ok(Self(map)))
+			.and(Ok(Self { date, missing: Vec::new(), rates: map }))
 	}
 }
 
 #[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping, reason = "digits are grouped by decimal place")]
 mod tests
 {
+	use chrono::NaiveDate;
 	use pretty_assertions::assert_eq;
 
 	use super::ExchangeRates;
-	use crate::{Currency, Decimal, SAMPLE_EXCHANGE_RATES_CSV};
+	use crate::{Currency, Decimal, Error, SAMPLE_EXCHANGE_RATES_CSV};
 
 	#[tokio::test]
 	async fn new()
 	{
 		assert_eq!(
 			SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap(),
-			ExchangeRates(
-				[
+			ExchangeRates {
+				date:    NaiveDate::from_ymd_opt(2021, 6, 3),
+				missing: Vec::new(),
+				rates:   [
 					(Currency::Aud, Decimal::new(1_5792, 4)),
 					(Currency::Bgn, Decimal::new(1_9558, 4)),
 					(Currency::Brl, Decimal::new(6_1894, 4)),
@@ -89,7 +127,45 @@ mod tests
 				]
 				.into_iter()
 				.collect(),
-			),
+			},
 		);
 	}
+
+	#[test]
+	fn not_positive()
+	{
+		["0", "-1.2187"].into_iter().for_each(|rate| {
+			let csv = format!("Date, USD, JPY, \n03 June 2021, {rate}, 133.81, ");
+			assert!(matches!(
+				csv.parse::<ExchangeRates>(),
+				Err(Error::Decode { reason, .. }) if reason.contains("USD") && reason.contains(rate)
+			));
+		});
+	}
+
+	#[test]
+	fn trailing_comma()
+	{
+		let with = "Date, USD, JPY, \n03 June 2021, 1.2187, 133.81, ";
+		let without = "Date, USD, JPY\n03 June 2021, 1.2187, 133.81";
+		let mixed = "Date, USD, JPY, \n03 June 2021, 1.2187, 133.81";
+
+		let expected = with.parse::<ExchangeRates>().unwrap();
+		assert_eq!(expected.rates.len(), 3);
+		assert_eq!(without.parse::<ExchangeRates>().unwrap(), expected);
+		assert_eq!(mixed.parse::<ExchangeRates>().unwrap(), expected);
+	}
+
+	#[test]
+	fn trim_csv_line()
+	{
+		assert!(super::has_trailing_comma("Date, USD, JPY, "));
+		assert!(!super::has_trailing_comma("Date, USD, JPY"));
+
+		assert_eq!(super::trim_csv_line("Date,USD,JPY,", true), "Date,USD,JPY");
+		assert_eq!(super::trim_csv_line("Date, USD, JPY, ", true), "Date, USD, JPY");
+		assert_eq!(super::trim_csv_line("Date,USD,JPY", true), "Date,USD,JPY");
+		assert_eq!(super::trim_csv_line("2021-06-03,1.2187,,", true), "2021-06-03,1.2187,");
+		assert_eq!(super::trim_csv_line("2021-06-03,1.2187, ", false), "2021-06-03,1.2187,");
+	}
 }
@@ -0,0 +1,51 @@
+use crate::{Currency, Exchange, ExchangeRates};
+
+/// Something which was [exchanged](Exchange::exchange) into another [`Currency`], alongside what
+/// it was before being exchanged (e.g. to keep a record of the original amount in an audit log).
+///
+/// # See also
+///
+/// * [`Money::exchange_tracked`](crate::Money::exchange_tracked), to create [`Exchanged`]
+///   [`Money`](crate::Money).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Exchanged<T>
+{
+	/// What was exchanged, before it was exchanged.
+	pub original: T,
+
+	/// The `original` after it was exchanged.
+	pub converted: T,
+}
+
+impl<T> Exchange for Exchanged<T>
+where
+	T: Exchange,
+{
+	/// Exchange the `converted` value again, keeping the `original` as-is.
+	fn exchange_mut(&mut self, currency: Currency, rates: &ExchangeRates)
+	{
+		self.converted.exchange_mut(currency, rates);
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use pretty_assertions::assert_eq;
+
+	use super::Exchanged;
+	use crate::{Currency, Exchange, ExchangeRates, Money, SAMPLE_EXCHANGE_RATES_CSV};
+
+	#[test]
+	fn exchange()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		let usd = Money::new(20_00, 2, Currency::Usd);
+
+		let eur = usd.exchange(Currency::Eur, &rates);
+		let exchanged = Exchanged { original: usd, converted: eur }.exchange(Currency::Jpy, &rates);
+
+		assert_eq!(exchanged.original, usd);
+		assert_eq!(exchanged.converted, eur.exchange(Currency::Jpy, &rates));
+	}
+}
@@ -0,0 +1,31 @@
+/// Options which control how [`Money::format_with`](crate::Money::format_with) displays
+/// [`Money`](crate::Money).
+///
+/// The [`Default`] is the same as the [`Display`](core::fmt::Display) of [`Money`](crate::Money)
+/// (e.g. `-20.00 USD`).
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FormatOptions
+{
+	/// How a negative amount is distinguished from a positive one.
+	pub negative_style: NegativeStyle,
+
+	/// Whether the [symbol](crate::Currency::symbol) of the [`Currency`](crate::Currency) is
+	/// written before the amount (e.g. `$20.00`), rather than its code after (e.g. `20.00 USD`).
+	pub symbol: bool,
+}
+
+/// How a negative amount of [`Money`](crate::Money) is displayed.
+///
+/// # See also
+///
+/// * [`FormatOptions::negative_style`]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum NegativeStyle
+{
+	/// Prefix the amount with a minus sign (e.g. `-$20.00`).
+	#[default]
+	Minus,
+
+	/// Wrap the amount in parentheses, as is the convention in accounting (e.g. `($20.00)`).
+	Parentheses,
+}
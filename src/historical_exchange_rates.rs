@@ -1,12 +1,40 @@
+#[cfg(feature = "network")]
+use core::{future::Future, ops::Deref};
 use std::{
 	collections::{BTreeMap, HashMap},
+	io::{BufRead, BufReader, Read},
+	sync::{PoisonError, RwLock as StdRwLock},
+};
+#[cfg(feature = "network")]
+use std::{
+	fs,
+	path::{Path, PathBuf},
 	sync::OnceLock as StdOnceLock,
 };
 
-use chrono::{DateTime, Duration, Local, NaiveDate};
-use tokio::sync::{OnceCell, RwLock, RwLockReadGuard};
+#[cfg(all(feature = "network", not(feature = "tokio")))]
+use async_lock::{OnceCell, RwLock};
+use chrono::{DateTime, Local, NaiveDate};
+#[cfg(feature = "network")]
+use chrono::{Datelike, Duration};
+#[cfg(feature = "futures")]
+use futures_util::{stream, Stream};
+#[cfg(all(feature = "network", feature = "tokio"))]
+use tokio::sync::{OnceCell, RwLock};
 
-use crate::{request, Currency, Decimal, Error, Exchange, ExchangeRates, Result};
+#[cfg(feature = "network")]
+use crate::{cache, request};
+use crate::{
+	exchange_rates::{has_trailing_comma, trim_csv_line},
+	Clock,
+	Currency,
+	Decimal,
+	Error,
+	Exchange,
+	ExchangeRates,
+	LocalClock,
+	Result,
+};
 
 /// A collection of rates of exchange between currencies such that some `amount` of
 /// [`Money`](crate::Money) divided by its [`Currency`] will yield [`Currency::Eur`], and an
@@ -15,41 +43,332 @@ use crate::{request, Currency, Decimal, Error, Exchange, ExchangeRates, Result};
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct HistoricalExchangeRates;
 
-type HistoricalExchangeMap = BTreeMap<NaiveDate, ExchangeRates>;
+/// A historical record of [`ExchangeRates`], keyed by the date they are from.
+///
+/// This is what the functions of [`HistoricalExchangeRates`] which end in `_from` (e.g.
+/// [`HistoricalExchangeRates::exchange_from`]) accept, so a record can be built by hand from any
+/// source of dated rates (rather than [downloaded](HistoricalExchangeRates::history) from the
+/// [ECB][ecb]).
+///
+/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+///
+/// # Examples
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// use chrono::{Local, NaiveDate, TimeZone};
+/// use money2::{
+///   Currency,
+///   Decimal,
+///   ExchangeRates,
+///   HistoricalExchangeMap,
+///   HistoricalExchangeRates,
+///   Money,
+/// };
+///
+/// let rates: ExchangeRates = [(Currency::Usd, Decimal::new(1_25, 2))].into_iter().collect();
+/// let day = NaiveDate::from_ymd_opt(2021, 6, 3).unwrap();
+/// let history = HistoricalExchangeMap::from([(day, rates)]);
+/// let date = Local.with_ymd_and_hms(2021, 6, 3, 0, 0, 0).earliest();
+///
+/// assert_eq!(
+///   HistoricalExchangeRates::exchange_from(
+///     &history,
+///     date,
+///     Currency::Eur,
+///     Money::new(12_00, 2, Currency::Usd),
+///   ),
+///   Money::new(9_60, 2, Currency::Eur),
+/// );
+/// ```
+pub type HistoricalExchangeMap = BTreeMap<NaiveDate, ExchangeRates>;
+#[cfg(feature = "network")]
 type HistoricalExchangeLock = RwLock<HistoricalExchangeMap>;
 
-/// Gets the [`Local`] time and converts it to a [`NaiveDateTime`].
-fn local_now() -> NaiveDate
+/// The [`Clock`] used by [`HistoricalExchangeRates`], if not the [`LocalClock`].
+static CLOCK: StdRwLock<Option<Box<dyn Clock>>> = StdRwLock::new(None);
+
+/// How long the historical record managed by [`HistoricalExchangeRates`] is used before it is
+/// refreshed, if not one day.
+#[cfg(feature = "network")]
+static REFRESH_INTERVAL: StdRwLock<Option<Duration>> = StdRwLock::new(None);
+
+/// Gets the current date according to the [`Clock`] used by [`HistoricalExchangeRates`].
+fn today() -> NaiveDate
+{
+	let clock = CLOCK.read().unwrap_or_else(PoisonError::into_inner);
+	clock.as_deref().map_or_else(|| LocalClock.today(), Clock::today)
+}
+
+/// Whether the historical record should be refreshed, given the date it was `last_checked`, the
+/// date it is `now`, and the `interval` between refreshes.
+#[cfg(feature = "network")]
+fn needs_refresh(last_checked: NaiveDate, now: NaiveDate, interval: Duration) -> bool
+{
+	now.signed_duration_since(last_checked) >= interval
+}
+
+/// Gets the [`REFRESH_INTERVAL`], or one day if it was not set.
+#[cfg(feature = "network")]
+fn refresh_interval() -> Duration
+{
+	let interval = REFRESH_INTERVAL.read().unwrap_or_else(PoisonError::into_inner);
+	interval.unwrap_or_else(|| Duration::days(1))
+}
+
+/// Parse the header `line` of a historical CSV into the [`Currency`] of each column of rates.
+fn parse_csv_headers(line: &str) -> Vec<Option<Currency>>
 {
-	Local::now().naive_local().date()
+	trim_csv_line(line, has_trailing_comma(line))
+		.split(',')
+		.skip(1)
+		.map(Currency::reverse_lookup)
+		.collect()
+}
+
+/// Parse a `line` of a historical CSV into its date and [`ExchangeRates`], using the `headers`
+/// from [`parse_csv_headers`]. The `trailing_comma` is removed when the header line had one (see
+/// [`has_trailing_comma`]).
+///
+/// # Errors
+///
+/// * [`Error::Decode`] if the `line` does not have the same number of columns as the `headers`.
+///   The `line_number` is used to describe where the error occurred.
+/// * [`Error::Decode`] if any rate on the `line` is zero or negative.
+fn parse_csv_row(
+	headers: &[Option<Currency>],
+	trailing_comma: bool,
+	line_number: usize,
+	line: &str,
+) -> Result<(NaiveDate, ExchangeRates)>
+{
+	let line = trim_csv_line(line, trailing_comma);
+	let columns = line.split(',').count();
+	if columns != headers.len() + 1
+	{
+		return Err(Error::Decode {
+			context: "the historical exchange rates CSV from the ECB".into(),
+			reason:  format!(
+				"line {line_number} had {columns} columns, but the headers had {}",
+				headers.len() + 1
+			),
+		});
+	}
+
+	let mut values = line.split(',');
+	let date = values.next().and_then(|d| d.parse::<NaiveDate>().ok()).unwrap_or_default();
+
+	let mut rates = headers.iter().zip(values).try_fold(
+		ExchangeRates { date: Some(date), missing: Vec::new(), rates: HashMap::new() },
+		|mut rates, (header, value)| -> Result<ExchangeRates> {
+			if let Some(c) = header
+			{
+				// NOTE: the ECB uses `N/A` for currencies which were not reported on the `date`
+				match value.parse::<Decimal>()
+				{
+					Ok(d) if d.is_sign_negative() || d.is_zero() =>
+					{
+						return Err(Error::csv_rate_not_positive(*c, d))
+					},
+					Ok(d) => drop(rates.rates.insert(*c, d)),
+					Err(_) => rates.missing.push(*c),
+				}
+			}
+
+			Ok(rates)
+		},
+	)?;
+
+	// NOTE: conversion to EUR is not stored in ECB exchange rates, since the rates are given in
+	//       context of EUR to some other currency.
+	rates.rates.insert(Currency::Eur, 1.into());
+	Ok((date, rates))
 }
 
 impl HistoricalExchangeRates
 {
+	/// Like [`HistoricalExchangeRates::available_dates_from`], but uses an automatically-managed
+	/// source of historical data from the ECB.
+	///
+	/// Returns an [`Err`] if something went wrong retrieving the historical data.
+	///
+	/// # See also
+	///
+	/// * [`HistoricalExchangeRates::available_dates_from`]
+	#[cfg(feature = "network")]
+	pub async fn available_dates() -> Result<Vec<NaiveDate>>
+	{
+		let history = Self::history().await?;
+		Ok(Self::available_dates_from(&history))
+	}
+
+	/// Retrieve every date which has [`ExchangeRates`] in the `history`, in ascending order (e.g.
+	/// to offer a choice of dates in a user interface).
+	///
+	/// # See also
+	///
+	/// * [`HistoricalExchangeRates::history`]
+	/// * [`HistoricalExchangeRates::parse_csv`]
+	pub fn available_dates_from(history: &HistoricalExchangeMap) -> Vec<NaiveDate>
+	{
+		history.keys().copied().collect()
+	}
+
+	/// Like [`HistoricalExchangeRates::average_rate_from`], but uses an automatically-managed
+	/// source of historical data from the ECB.
+	///
+	/// Returns an [`Err`] if something went wrong retrieving the historical data.
+	///
+	/// # See also
+	///
+	/// * [`HistoricalExchangeRates::average_rate_from`]
+	#[cfg(feature = "network")]
+	pub async fn average_rate(
+		start: NaiveDate,
+		end: NaiveDate,
+		current: Currency,
+		desired: Currency,
+	) -> Result<Option<Decimal>>
+	{
+		let history = Self::history().await?;
+		Ok(Self::average_rate_from(&history, start, end, current, desired))
+	}
+
+	/// Retrieve the mean rate of exchange from the `current` to the `desired` [`Currency`] across
+	/// every date in the `history` which is between the `start` and `end` dates (inclusive).
+	///
+	/// Returns [`None`] if there are no [`ExchangeRates`] in that range which have both the
+	/// `current` and `desired` [`Currency`].
+	///
+	/// # See also
+	///
+	/// * [`ExchangeRates::get`]
+	/// * [`HistoricalExchangeRates::history`]
+	/// * [`HistoricalExchangeRates::parse_csv`]
+	/// * [`HistoricalExchangeRates::range_from`]
+	pub fn average_rate_from(
+		history: &HistoricalExchangeMap,
+		start: NaiveDate,
+		end: NaiveDate,
+		current: Currency,
+		desired: Currency,
+	) -> Option<Decimal>
+	{
+		if start > end
+		{
+			return None;
+		}
+
+		let (sum, count) = history
+			.range(start..=end)
+			.filter_map(|(_, rates)| rates.get(&current, &desired))
+			.fold((Decimal::ZERO, 0u32), |(sum, count), rate| (sum + rate, count + 1));
+
+		(count > 0).then(|| sum / Decimal::from(count))
+	}
+
 	/// The single in-memory representation of the [`HistoricalExchangeMap`].
+	#[cfg(feature = "network")]
 	pub(crate) async fn cached() -> Result<&'static HistoricalExchangeLock>
 	{
+		#[cfg(not(feature = "tokio"))]
+		static CELL: OnceCell<HistoricalExchangeLock> = OnceCell::new();
+		#[cfg(feature = "tokio")]
 		static CELL: OnceCell<HistoricalExchangeLock> = OnceCell::const_new();
 		static LAST_CHECK: StdOnceLock<RwLock<NaiveDate>> = StdOnceLock::new();
 
-		let cached = CELL
+		Self::cached_in(&CELL, &LAST_CHECK, today(), refresh_interval(), Self::from_ecb_on).await
+	}
+
+	/// Like [`HistoricalExchangeRates::cached`], but the [`HistoricalExchangeMap`] is kept in the
+	/// given `cell`, and the date it was last checked in `last_check`. When the `cell` is empty, or
+	/// [`needs_refresh`] given the date it is `now` and the `interval`, it is replaced with the
+	/// record `from_ecb` on that date.
+	#[cfg(feature = "network")]
+	async fn cached_in<'cell, F, Fut>(
+		cell: &'cell OnceCell<HistoricalExchangeLock>,
+		last_check: &'cell StdOnceLock<RwLock<NaiveDate>>,
+		now: NaiveDate,
+		interval: Duration,
+		from_ecb: F,
+	) -> Result<&'cell HistoricalExchangeLock>
+	where
+		F: Fn(NaiveDate) -> Fut,
+		Fut: Future<Output = Result<HistoricalExchangeMap>>,
+	{
+		let cached = cell
 			.get_or_try_init(|| async {
-				let map = Self::from_ecb().await?;
-				LAST_CHECK.set(local_now().into()).ok();
+				let map = from_ecb(now).await?;
+				last_check.set(now.into()).ok();
 				Result::Ok(RwLock::new(map))
 			})
 			.await?;
 
-		let now = local_now();
-		if LAST_CHECK.get_or_init(|| local_now().into()).read().await.signed_duration_since(now) >=
-			Duration::days(1)
+		let last_check = last_check.get_or_init(|| now.into());
+		if needs_refresh(*last_check.read().await, now, interval)
+		{
+			let mut last_check = last_check.write().await;
+
+			// NOTE: another task may have refreshed the history while this one waited for the lock
+			if needs_refresh(*last_check, now, interval)
+			{
+				let history = from_ecb(now).await?;
+				*cached.write().await = history;
+				*last_check = now;
+			}
+		}
+
+		Ok(cached)
+	}
+
+	/// Like [`HistoricalExchangeRates::cached`], but for the `blocking` functions. The date which
+	/// the [`HistoricalExchangeMap`] was last checked is stored alongside it.
+	///
+	/// This is separate from [`HistoricalExchangeRates::cached`] because it uses a synchronous
+	/// lock, but both read from the same file cache.
+	#[cfg(feature = "blocking")]
+	fn cached_blocking() -> Result<&'static StdRwLock<(NaiveDate, HistoricalExchangeMap)>>
+	{
+		static CELL: StdOnceLock<StdRwLock<(NaiveDate, HistoricalExchangeMap)>> =
+			StdOnceLock::new();
+
+		Self::cached_blocking_in(&CELL, today(), refresh_interval(), |date| {
+			Self::from_ecb_blocking(&Self::filepath_in(&ExchangeRates::cache_dir(), date))
+		})
+	}
+
+	/// Like [`HistoricalExchangeRates::cached_in`], but for
+	/// [`HistoricalExchangeRates::cached_blocking`].
+	#[cfg(feature = "blocking")]
+	fn cached_blocking_in<F>(
+		cell: &StdOnceLock<StdRwLock<(NaiveDate, HistoricalExchangeMap)>>,
+		now: NaiveDate,
+		interval: Duration,
+		from_ecb: F,
+	) -> Result<&StdRwLock<(NaiveDate, HistoricalExchangeMap)>>
+	where
+		F: Fn(NaiveDate) -> Result<HistoricalExchangeMap>,
+	{
+		// TODO: use `get_or_try_init` after rust-lang/rust#109737
+		let cached = match cell.get()
 		{
-			let mut history = cached.write().await;
-			*history = Self::from_ecb().await?;
-			drop(history);
+			Some(cached) => cached,
+			None =>
+			{
+				let history = from_ecb(now)?;
+				cell.get_or_init(|| StdRwLock::new((now, history)))
+			},
+		};
 
-			let mut last_check = LAST_CHECK.get_or_init(|| local_now().into()).write().await;
-			*last_check = now;
+		if needs_refresh(cached.read().unwrap_or_else(PoisonError::into_inner).0, now, interval)
+		{
+			let mut cached = cached.write().unwrap_or_else(PoisonError::into_inner);
+
+			// NOTE: another thread may have refreshed the history while this one waited
+			if needs_refresh(cached.0, now, interval)
+			{
+				*cached = (now, from_ecb(now)?);
+			}
 		}
 
 		Ok(cached)
@@ -64,6 +383,7 @@ impl HistoricalExchangeRates
 	/// # See also
 	///
 	/// * [`HistoricalExchangeRates::exchange_from`]
+	#[cfg(feature = "network")]
 	pub async fn exchange<E>(
 		date: Option<DateTime<Local>>,
 		currency: Currency,
@@ -75,11 +395,12 @@ impl HistoricalExchangeRates
 		Self::try_exchange(date, currency, exchangeable).await.unwrap()
 	}
 
-	/// Like [`HistoricalExchangeMap::exchange_opt_from`] but panics when it would return [`None`].
+	/// Like [`HistoricalExchangeRates::exchange_opt_from`] but panics when it would return
+	/// [`None`].
 	///
 	/// # Panics
 	///
-	/// * When [`HistoricalExchangeMap::exchange_opt_from`] would return [`None`].
+	/// * When [`HistoricalExchangeRates::exchange_opt_from`] would return [`None`].
 	///
 	/// # See also
 	///
@@ -107,6 +428,7 @@ impl HistoricalExchangeRates
 	/// # See also
 	///
 	/// * [`HistoricalExchangeRates::try_exchange`]
+	#[cfg(feature = "network")]
 	pub async fn exchange_opt<E>(
 		date: Option<DateTime<Local>>,
 		currency: Currency,
@@ -144,16 +466,101 @@ impl HistoricalExchangeRates
 	/// Download the latest historical record of exchange rate data from the [ECB][ecb] and parse it
 	/// into a [`HistoricalExchangeMap`].
 	///
+	/// The download is cached at the [filepath](HistoricalExchangeRates::filepath_in) of the given
+	/// `date` in the cache directory.
+	///
 	/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
-	async fn from_ecb() -> Result<HistoricalExchangeMap>
+	#[cfg(feature = "network")]
+	async fn from_ecb_on(date: NaiveDate) -> Result<HistoricalExchangeMap>
+	{
+		let path = Self::filepath_in(&ExchangeRates::cache_dir(), date);
+		Self::from_ecb_cached_at(&request::client()?, path).await
+	}
+
+	/// Like [`HistoricalExchangeRates::from_ecb_on`], but reads the historical record from the file
+	/// at `path` if it exists. Otherwise, the download is made with the `client` and written to
+	/// `path`.
+	#[cfg(feature = "network")]
+	async fn from_ecb_cached_at(
+		client: &reqwest::Client,
+		path: PathBuf,
+	) -> Result<HistoricalExchangeMap>
+	{
+		let url = "https://www.ecb.europa.eu/stats/eurofxref/eurofxref-hist.zip";
+		let csv = cache::get_unzipped(client, url, &path).await?;
+		Self::remove_stale(&path);
+		Self::parse_csv(&csv)
+	}
+
+	/// Download the last 90 days of exchange rate data from the [ECB][ecb] and parse it into a
+	/// [`HistoricalExchangeMap`].
+	///
+	/// PERF: this is much smaller than the full historical record, which is downloaded by the
+	///       automatically-managed source of historical data. Use it with the `_from` functions
+	///       (e.g. [`HistoricalExchangeRates::get_from`]) if only recent data is needed.
+	///
+	/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+	#[cfg(feature = "network")]
+	pub async fn from_ecb_recent() -> Result<HistoricalExchangeMap>
 	{
 		let csv =
-			request::get_unzipped("https://www.ecb.europa.eu/stats/eurofxref/eurofxref-hist.zip")
+			request::get_unzipped("https://www.ecb.europa.eu/stats/eurofxref/eurofxref-hist-90d.zip")
 				.await?;
 
 		Self::parse_csv(&csv)
 	}
 
+	/// Download the historical record of exchange rate data from the [ECB][ecb] with the given
+	/// `client` (e.g. one with custom TLS certificates, proxies, or headers), and parse it into a
+	/// [`HistoricalExchangeMap`]. The download is cached in the same file as the
+	/// automatically-managed source of historical data.
+	///
+	/// Unlike [`ExchangeRates::set_client`], the `client` is only used for this download.
+	///
+	/// [ecb]: https://www.ecb.europa.eu/stats/policy_and_exchange_rates/euro_reference_exchange_rates/
+	#[cfg(feature = "network")]
+	pub async fn from_ecb_with_client(client: &reqwest::Client) -> Result<HistoricalExchangeMap>
+	{
+		Self::from_ecb_cached_at(client, Self::filepath()).await
+	}
+
+	/// Like [`HistoricalExchangeRates::from_ecb_cached_at`], but blocks the current thread instead
+	/// of being `async`, and the download is made with the blocking client.
+	#[cfg(feature = "blocking")]
+	fn from_ecb_blocking(path: &Path) -> Result<HistoricalExchangeMap>
+	{
+		let url = "https://www.ecb.europa.eu/stats/eurofxref/eurofxref-hist.zip";
+		let csv = cache::get_unzipped_blocking(url, path)?;
+		Self::remove_stale(path);
+		Self::parse_csv(&csv)
+	}
+
+	/// Return the [filepath](PathBuf) which the latest historical record should be stored at.
+	///
+	/// There will be a new filepath each day.
+	///
+	/// # See also
+	///
+	/// * [`ExchangeRates::set_cache_dir`]
+	#[cfg(feature = "network")]
+	fn filepath() -> PathBuf
+	{
+		Self::filepath_in(&ExchangeRates::cache_dir(), today())
+	}
+
+	/// Return the [filepath](PathBuf) in the given `dir`ectory which the historical record from
+	/// the given `date` should be stored at.
+	#[cfg(feature = "network")]
+	fn filepath_in(dir: &Path, date: NaiveDate) -> PathBuf
+	{
+		dir.join(format!(
+			"money2--historical--{}-{}-{}.csv",
+			date.year(),
+			date.month(),
+			date.day()
+		))
+	}
+
 	/// Like [`get_from`], but uses an automatically-managed source of historical data from the ECB.
 	///
 	/// Returns an [`Err`] if something went wrong retrieving the historical
@@ -163,12 +570,30 @@ impl HistoricalExchangeRates
 	/// # See also
 	///
 	/// * [`HistoricalExchangeRates::get_from`]
+	#[cfg(feature = "network")]
 	pub async fn get(date: Option<DateTime<Local>>) -> Result<Option<ExchangeRates>>
 	{
 		let history = Self::history().await?;
 		Ok(Self::get_from(&history, date))
 	}
 
+	/// Like [`HistoricalExchangeRates::get`], but blocks the current thread instead of being
+	/// `async`.
+	///
+	/// The historical record is kept in memory between calls, and is only read again once the
+	/// [refresh interval](HistoricalExchangeRates::set_refresh_interval) has passed.
+	///
+	/// # Panics
+	///
+	/// * When called from within an `async` runtime, because [`reqwest::blocking`] does.
+	#[cfg(feature = "blocking")]
+	pub fn get_blocking(date: Option<DateTime<Local>>) -> Result<Option<ExchangeRates>>
+	{
+		let cached = Self::cached_blocking()?;
+		let (_, history) = &*cached.read().unwrap_or_else(PoisonError::into_inner);
+		Ok(Self::get_from(history, date))
+	}
+
 	/// Like [`HistoricalExchangeRates::get_ref_from`] but return and owned value.
 	///
 	/// # See also
@@ -184,6 +609,23 @@ impl HistoricalExchangeRates
 		Self::get_ref_from(history, date).cloned()
 	}
 
+	/// Like [`HistoricalExchangeRates::get_ref_from`], except only the nearest-available date
+	/// which is not after the given `date` (or today if [`None`]) is used. Returns [`None`] if
+	/// there are no [`ExchangeRates`] on or before that date.
+	///
+	/// # See also
+	///
+	/// * [`HistoricalExchangeRates::history`]
+	/// * [`HistoricalExchangeRates::parse_csv`]
+	pub fn get_ref_before(
+		history: &HistoricalExchangeMap,
+		date: Option<DateTime<Local>>,
+	) -> Option<&ExchangeRates>
+	{
+		let naive = date.map_or_else(today, |d| d.naive_local().date());
+		history.range(..=naive).next_back().map(|(_, rates)| rates)
+	}
+
 	/// Retrieve the [`ExchangeRates`] from the given `date` (or the nearest-available date;
 	/// today if [`None`]). Returns [`Some(rates)`] or [`None`] to indicate the presence or absence
 	/// of the rates in the historical record.
@@ -197,11 +639,17 @@ impl HistoricalExchangeRates
 		date: Option<DateTime<Local>>,
 	) -> Option<&ExchangeRates>
 	{
-		let naive = date.map_or_else(local_now, |d| d.naive_local().date());
+		Self::get_ref_from_at(history, date.map_or_else(today, |d| d.naive_local().date()))
+	}
+
+	/// Like [`HistoricalExchangeRates::get_ref_from`], but the `date` is always given rather than
+	/// being today according to the [`Clock`].
+	fn get_ref_from_at(history: &HistoricalExchangeMap, date: NaiveDate) -> Option<&ExchangeRates>
+	{
 		history
-			.range(..=naive)
+			.range(..=date)
 			.next_back()
-			.or_else(|| history.range(naive..).next())
+			.or_else(|| history.range(date..).next())
 			.map(|(_, rates)| rates)
 	}
 
@@ -209,11 +657,15 @@ impl HistoricalExchangeRates
 	/// pulling asynchrony out from a loop, and then passing the value manually to
 	/// [`ExchangeRates::get_ref_from`].
 	///
+	/// The return value is a read guard which dereferences to the [`HistoricalExchangeMap`]. Its
+	/// concrete type is unspecified, since it depends on whether the `tokio` feature is enabled.
+	///
 	/// # Warnings
 	///
 	/// * While the return value is in scope, the [`HistoricalExchangeRates`] cannot update itself!
 	///   **This may cause other operations to lock until this value is released**.
-	pub async fn history() -> Result<RwLockReadGuard<'static, HistoricalExchangeMap>>
+	#[cfg(feature = "network")]
+	pub async fn history() -> Result<impl Deref<Target = HistoricalExchangeMap>>
 	{
 		let cached = Self::cached().await?;
 		Ok(cached.read().await)
@@ -228,6 +680,7 @@ impl HistoricalExchangeRates
 	/// # See also
 	///
 	/// * [`HistoricalExchangeRates::index_from`]
+	#[cfg(feature = "network")]
 	pub async fn index(date: Option<DateTime<Local>>) -> ExchangeRates
 	{
 		Self::try_index(date).await.unwrap()
@@ -269,6 +722,62 @@ impl HistoricalExchangeRates
 		Self::get_ref_from(history, date).unwrap()
 	}
 
+	/// Like [`HistoricalExchangeRates::interpolate_from`], but uses an automatically-managed source
+	/// of historical data from the ECB.
+	///
+	/// Returns an [`Err`] if something went wrong retrieving the historical data.
+	///
+	/// # See also
+	///
+	/// * [`HistoricalExchangeRates::interpolate_from`]
+	#[cfg(feature = "network")]
+	pub async fn interpolate(
+		date: NaiveDate,
+		current: Currency,
+		desired: Currency,
+	) -> Result<Option<Decimal>>
+	{
+		let history = Self::history().await?;
+		Ok(Self::interpolate_from(&history, date, current, desired))
+	}
+
+	/// Retrieve the rate of exchange from the `current` to the `desired` [`Currency`] on the given
+	/// `date`, by linearly interpolating between the nearest dates before and after it in the
+	/// `history` (e.g. to estimate the rate on a weekend).
+	///
+	/// Returns [`None`] if the `date` is not between two dates in the `history`, or the
+	/// [`ExchangeRates`] on either of them do not have the `current` or `desired` [`Currency`].
+	///
+	/// # See also
+	///
+	/// * [`ExchangeRates::get`]
+	/// * [`HistoricalExchangeRates::history`]
+	/// * [`HistoricalExchangeRates::parse_csv`]
+	pub fn interpolate_from(
+		history: &HistoricalExchangeMap,
+		date: NaiveDate,
+		current: Currency,
+		desired: Currency,
+	) -> Option<Decimal>
+	{
+		let (before_date, before) = history.range(..=date).next_back()?;
+		let before_rate = before.get(&current, &desired)?;
+		if *before_date == date
+		{
+			return Some(before_rate);
+		}
+
+		let (after_date, after) = history.range(date..).next()?;
+		let after_rate = after.get(&current, &desired)?;
+
+		let elapsed = date.signed_duration_since(*before_date).num_days();
+		let span = after_date.signed_duration_since(*before_date).num_days();
+		Some(
+			before_rate +
+				(after_rate - before_rate) * Decimal::from(elapsed) / Decimal::from(span),
+		)
+	}
+
 	/// Parse a CSV of the form:
 	///
 	/// ```csv
@@ -277,7 +786,9 @@ impl HistoricalExchangeRates
 	/// …
 	/// ```
 	///
-	/// Returns [`Ok(map)`] if the CSV was successfully parsed, otherwise returns [`Err`].
+	/// Returns [`Ok(map)`] if the CSV was successfully parsed, otherwise returns [`Err`] (e.g. when
+	/// a row does not have as many columns as the headers, there are no rows after the headers, or
+	/// a rate is zero or negative).
 	///
 	/// # Additional Details
 	///
@@ -288,45 +799,226 @@ impl HistoricalExchangeRates
 	/// However, if there is a need to manually parse this data, the option is available.
 	pub fn parse_csv(csv: &str) -> Result<HistoricalExchangeMap>
 	{
-		let mut lines = csv.lines().map(|line| line.split(','));
-		let headers: Vec<_> = lines
-			.next()
-			.map(|split| split.skip(1).map(Currency::reverse_lookup).collect())
-			.ok_or_else(|| Error::csv_row_missing("headers"))?;
+		let mut lines = csv.lines();
+		let header = lines.next().ok_or_else(|| Error::csv_row_missing("headers"))?;
+		let headers = parse_csv_headers(header);
+		let trailing_comma = has_trailing_comma(header);
 
-		Ok(lines.fold(BTreeMap::new(), |mut m, mut values| {
-			let date = values.next().and_then(|d| d.parse::<NaiveDate>().ok()).unwrap_or_default();
+		// NOTE: the headers are on line 1
+		let history: HistoricalExchangeMap = lines
+			.zip(2..)
+			.map(|(line, number)| parse_csv_row(&headers, trailing_comma, number, line))
+			.collect::<Result<_>>()?;
 
-			let mut rates = headers.iter().zip(values).fold(
-				ExchangeRates(HashMap::new()),
-				|mut rates, (header, value)| {
-					// TODO: if-let chain
-					if let Some(c) = header
-					{
-						if let Ok(d) = value.parse::<Decimal>()
-						{
-							rates.0.insert(*c, d);
-						}
-					}
-
-					rates
-				},
-			);
-
-			// NOTE: conversion to EUR is not stored in ECB exchange rates, since the rates are
-			// given in       context of EUR to some other currency.
-			rates.0.insert(Currency::Eur, 1.into());
-			m.insert(date, rates);
-			m
+		match history.is_empty()
+		{
+			false => Ok(history),
+			_ => Err(Error::csv_row_missing("data")),
+		}
+	}
+
+	/// Like [`HistoricalExchangeRates::parse_csv`], but the CSV is read line-by-line from the
+	/// `reader` rather than being held in memory all at once.
+	///
+	/// # Errors
+	///
+	/// * When [`HistoricalExchangeRates::parse_csv`] does.
+	/// * When the `reader` fails to read a line.
+	pub fn parse_csv_reader<R>(reader: R) -> Result<HistoricalExchangeMap>
+	where
+		R: Read,
+	{
+		let mut lines = BufReader::new(reader).lines();
+		let header = lines.next().transpose()?.ok_or_else(|| Error::csv_row_missing("headers"))?;
+		let headers = parse_csv_headers(&header);
+		let trailing_comma = has_trailing_comma(&header);
+
+		// NOTE: the headers are on line 1
+		let history: HistoricalExchangeMap = lines
+			.zip(2..)
+			.map(|(line, number)| parse_csv_row(&headers, trailing_comma, number, &line?))
+			.collect::<Result<_>>()?;
+
+		match history.is_empty()
+		{
+			false => Ok(history),
+			_ => Err(Error::csv_row_missing("data")),
+		}
+	}
+
+	/// Like [`HistoricalExchangeRates::range_from`], but uses an automatically-managed source of
+	/// historical data from the ECB.
+	///
+	/// Returns an [`Err`] if something went wrong retrieving the historical data.
+	///
+	/// # See also
+	///
+	/// * [`HistoricalExchangeRates::range_from`]
+	#[cfg(feature = "network")]
+	pub async fn range(start: NaiveDate, end: NaiveDate) -> Result<Vec<(NaiveDate, ExchangeRates)>>
+	{
+		let history = Self::history().await?;
+		Ok(Self::range_from(&history, start, end))
+	}
+
+	/// Retrieve every [`ExchangeRates`] in the `history` which is between the `start` and `end`
+	/// dates (inclusive), in ascending order by date. If `start` is after `end`, nothing is
+	/// returned.
+	///
+	/// # See also
+	///
+	/// * [`HistoricalExchangeRates::history`]
+	/// * [`HistoricalExchangeRates::parse_csv`]
+	pub fn range_from(
+		history: &HistoricalExchangeMap,
+		start: NaiveDate,
+		end: NaiveDate,
+	) -> Vec<(NaiveDate, ExchangeRates)>
+	{
+		match start > end
+		{
+			false => history.range(start..=end).map(|(d, rates)| (*d, rates.clone())).collect(),
+			_ => Vec::new(),
+		}
+	}
+
+	/// Remove the historical records cached in the same directory as the one at the `path` which
+	/// are from an earlier date, since they will never be read again.
+	#[cfg(feature = "network")]
+	fn remove_stale(path: &Path)
+	{
+		let date_of = |path: &Path| {
+			let name = path.file_name()?.to_str()?;
+			let date = name.strip_prefix("money2--historical--")?.strip_suffix(".csv")?;
+			NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+		};
+
+		if let (Some(dir), Some(latest)) = (path.parent(), date_of(path))
+		{
+			fs::read_dir(dir)
+				.into_iter()
+				.flatten()
+				.filter_map(|entry| entry.ok().map(|e| e.path()))
+				.filter(|p| date_of(p).is_some_and(|date| date < latest))
+				// NOTE: another process may have removed the file already
+				.for_each(|p| drop(fs::remove_file(p)));
+		}
+	}
+
+	/// Like [`HistoricalExchangeRates::series_from`], but uses an automatically-managed source of
+	/// historical data from the ECB.
+	///
+	/// Returns an [`Err`] if something went wrong retrieving the historical data.
+	///
+	/// # See also
+	///
+	/// * [`HistoricalExchangeRates::series_from`]
+	#[cfg(feature = "network")]
+	pub async fn series(from: Currency, to: Currency) -> Result<Vec<(NaiveDate, Decimal)>>
+	{
+		let history = Self::history().await?;
+		Ok(Self::series_from(&history, from, to))
+	}
+
+	/// Retrieve the [rate](ExchangeRates::get) of exchange `from` one [`Currency`] `to` another on
+	/// every date in the `history` which accounts for both, in ascending order by date (e.g. to
+	/// plot the rate over time).
+	///
+	/// # See also
+	///
+	/// * [`HistoricalExchangeRates::history`]
+	/// * [`HistoricalExchangeRates::parse_csv`]
+	pub fn series_from(
+		history: &HistoricalExchangeMap,
+		from: Currency,
+		to: Currency,
+	) -> Vec<(NaiveDate, Decimal)>
+	{
+		history
+			.iter()
+			.filter_map(|(d, rates)| rates.get(&from, &to).map(|rate| (*d, rate)))
+			.collect()
+	}
+
+	/// Like [`HistoricalExchangeRates::stream_from`], but uses an automatically-managed source of
+	/// historical data from the ECB.
+	///
+	/// The historical data is only locked while each item is being retrieved, so that it may still
+	/// update itself while the stream is in use.
+	///
+	/// Returns an [`Err`] if something went wrong retrieving the historical data.
+	///
+	/// # See also
+	///
+	/// * [`HistoricalExchangeRates::range`], to collect the [`ExchangeRates`] into a [`Vec`].
+	#[cfg(all(feature = "futures", feature = "network"))]
+	pub async fn stream(
+		start: NaiveDate,
+		end: NaiveDate,
+	) -> Result<impl Stream<Item = (NaiveDate, ExchangeRates)>>
+	{
+		let cached = Self::cached().await?;
+		Ok(stream::unfold(Some(start), move |next| async move {
+			let next = next.filter(|n| *n <= end)?;
+			let history = cached.read().await;
+			let (date, rates) = history.range(next..=end).next()?;
+			Some(((*date, rates.clone()), date.succ_opt()))
 		}))
 	}
 
+	/// Same as [`HistoricalExchangeRates::range_from`], except each [`ExchangeRates`] is yielded
+	/// from a [`Stream`] rather than being collected into a [`Vec`].
+	///
+	/// # See also
+	///
+	/// * [`HistoricalExchangeRates::history`]
+	/// * [`HistoricalExchangeRates::parse_csv`]
+	#[cfg(feature = "futures")]
+	pub fn stream_from(
+		history: &HistoricalExchangeMap,
+		start: NaiveDate,
+		end: NaiveDate,
+	) -> impl Stream<Item = (NaiveDate, ExchangeRates)> + '_
+	{
+		let range = (start <= end).then(|| history.range(start..=end));
+		stream::iter(range.into_iter().flatten().map(|(d, rates)| (*d, rates.clone())))
+	}
+
+	/// Set the [`Clock`] which is used to determine the current date (e.g. when retrieving the
+	/// [`ExchangeRates`] for a `date` of [`None`], or deciding whether the historical record
+	/// managed by [`HistoricalExchangeRates`] is out of date).
+	///
+	/// By default, the [`LocalClock`] is used.
+	pub fn set_clock<C>(clock: C)
+	where
+		C: Clock + 'static,
+	{
+		*CLOCK.write().unwrap_or_else(PoisonError::into_inner) = Some(Box::new(clock));
+	}
+
+	/// Set how long the historical record managed by [`HistoricalExchangeRates`] is used before it
+	/// is downloaded again (e.g. longer for a daemon which does not need the latest rates). Since
+	/// the record is dated, the `interval` is effectively rounded up to a whole number of days,
+	/// according to the [`Clock`].
+	///
+	/// By default, the record is refreshed once per day.
+	///
+	/// # See also
+	///
+	/// * [`HistoricalExchangeRates::set_clock`]
+	#[cfg(feature = "network")]
+	pub fn set_refresh_interval(interval: Duration)
+	{
+		*REFRESH_INTERVAL.write().unwrap_or_else(PoisonError::into_inner) = Some(interval);
+	}
+
 	/// Like [`HistoricalExchangeRates::try_exchange_opt`] but panics when it would return
 	/// [`Ok(None)`].
 	///
 	/// # Panics
 	///
 	/// * When [`HistoricalExchangeRates::try_exchange_opt`] would return [`Ok(None)`].
+	#[cfg(feature = "network")]
 	pub async fn try_exchange<E>(
 		date: Option<DateTime<Local>>,
 		currency: Currency,
@@ -348,6 +1040,7 @@ impl HistoricalExchangeRates
 	/// * [`HistoricalExchangeRates::exchange_opt`]
 	/// * [`HistoricalExchangeRates::get_ref_from`] for a breakdown of how the history is searched
 	///   for `date`.
+	#[cfg(feature = "network")]
 	pub async fn try_exchange_opt<E>(
 		date: Option<DateTime<Local>>,
 		currency: Currency,
@@ -365,6 +1058,7 @@ impl HistoricalExchangeRates
 	/// # Panics
 	///
 	/// * When [`HistoricalExchangeRates::get`] return [`Ok(None)`].
+	#[cfg(feature = "network")]
 	pub async fn try_index(date: Option<DateTime<Local>>) -> Result<ExchangeRates>
 	{
 		Self::get(date)
@@ -374,22 +1068,140 @@ impl HistoricalExchangeRates
 }
 
 #[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping, reason = "digits are grouped by decimal place")]
 mod tests
 {
+	use std::io::Cursor;
+	#[cfg(feature = "network")]
+	use std::{env, fs, path::PathBuf, process};
+
+	#[cfg(feature = "futures")]
+	use futures_util::StreamExt;
 	use pretty_assertions::assert_eq;
 
+	#[cfg(feature = "network")]
+	use super::{request, Clock, Duration, LocalClock, OnceCell, StdOnceLock};
 	use super::{
 		Currency,
 		Decimal,
+		Error,
 		ExchangeRates,
+		HistoricalExchangeMap,
 		HistoricalExchangeRates,
 		Local,
 		NaiveDate,
 		Result,
 	};
-	use crate::Money;
+	use crate::{Money, SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV};
+
+	/// A [`Clock`] which is always on the given date.
+	#[cfg(feature = "network")]
+	struct FixedClock(NaiveDate);
 
+	#[cfg(feature = "network")]
+	impl Clock for FixedClock
+	{
+		fn today(&self) -> NaiveDate
+		{
+			self.0
+		}
+	}
+
+	/// Create a directory for the test with the given `name` to cache exchange rates in, so that
+	/// it does not share a cache with any other test.
+	#[cfg(feature = "network")]
+	fn cache_dir(name: &str) -> Result<PathBuf>
+	{
+		let dir = env::temp_dir().join(format!("money2--{name}--{}", process::id()));
+		fs::create_dir_all(&dir)?;
+		Ok(dir)
+	}
+
+	#[cfg(feature = "network")]
+	#[tokio::test]
+	async fn available_dates() -> Result<()>
+	{
+		let dates = HistoricalExchangeRates::available_dates().await?;
+		assert_eq!(dates.first(), NaiveDate::from_ymd_opt(1999, 1, 4).as_ref());
+		assert!(dates.windows(2).all(|w| w[0] < w[1]));
+		Ok(())
+	}
+
+	#[test]
+	fn available_dates_from() -> Result<()>
+	{
+		let history = HistoricalExchangeRates::parse_csv(SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV)?;
+		let date = |day| NaiveDate::from_ymd_opt(2021, 6, day).unwrap();
+
+		assert_eq!(HistoricalExchangeRates::available_dates_from(&history), [
+			date(1),
+			date(2),
+			date(3),
+			date(4),
+			date(7),
+		]);
+		assert_eq!(HistoricalExchangeRates::available_dates_from(&Default::default()), []);
+		Ok(())
+	}
+
+	#[test]
+	fn average_rate_from() -> Result<()>
+	{
+		let history = HistoricalExchangeRates::parse_csv(SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV)?;
+		let date = |day| NaiveDate::from_ymd_opt(2021, 6, day).unwrap();
+		let average = |start, end| {
+			HistoricalExchangeRates::average_rate_from(
+				&history,
+				date(start),
+				date(end),
+				Currency::Eur,
+				Currency::Usd,
+			)
+		};
+
+		assert_eq!(average(1, 3), Some(Decimal::new(1_2208, 4)));
+		assert_eq!(average(3, 3), Some(Decimal::new(1_2187, 4)));
+		assert_eq!(average(5, 6), None);
+		assert_eq!(average(3, 1), None);
+
+		Ok(())
+	}
+
+	#[test]
+	fn exchange_from()
+	{
+		let date = |day| NaiveDate::from_ymd_opt(2021, 6, day).unwrap();
+		let usd = |rate| [(Currency::Usd, rate)].into_iter().collect::<ExchangeRates>();
+		let history = HistoricalExchangeMap::from([
+			(date(1), usd(Decimal::new(1_20, 2))),
+			(date(3), usd(Decimal::new(1_25, 2))),
+		]);
+
+		let exchange = |day| {
+			HistoricalExchangeRates::exchange_opt_from(
+				&history,
+				date(day).and_hms_opt(0, 0, 0).and_then(|d| d.and_local_timezone(Local).earliest()),
+				Currency::Eur,
+				Money::new(12_00, 2, Currency::Usd),
+			)
+		};
+
+		assert_eq!(exchange(1), Some(Money::new(10_00, 2, Currency::Eur)));
+		assert_eq!(exchange(3), Some(Money::new(9_60, 2, Currency::Eur)));
+		assert_eq!(
+			HistoricalExchangeRates::exchange_opt_from(
+				&HistoricalExchangeMap::new(),
+				None,
+				Currency::Eur,
+				Money::new(12_00, 2, Currency::Usd),
+			),
+			None
+		);
+	}
+
+	#[cfg(feature = "network")]
 	#[tokio::test]
+	#[allow(clippy::zero_prefixed_literal, reason = "dates are zero-padded as in ISO 8601")]
 	async fn cached() -> Result<()>
 	{
 		let lock = HistoricalExchangeRates::cached().await?;
@@ -397,39 +1209,94 @@ mod tests
 
 		let (date, rates) = history.first_key_value().unwrap();
 		assert_eq!(date, &NaiveDate::from_ymd_opt(1999, 01, 04).unwrap());
-		assert_eq!(
-			rates,
-			&ExchangeRates(
-				[
-					(Currency::Aud, Decimal::new(1_91, 2)),
-					(Currency::Cad, Decimal::new(1_8004, 4)),
-					(Currency::Chf, Decimal::new(1_6168, 4)),
-					(Currency::Czk, Decimal::new(35_107, 3)),
-					(Currency::Dkk, Decimal::new(7_4501, 4)),
-					(Currency::Eur, 1.into()),
-					(Currency::Gbp, Decimal::new(0_7111, 4)),
-					(Currency::Hkd, Decimal::new(9_1332, 4)),
-					(Currency::Huf, Decimal::new(251_48, 2)),
-					(Currency::Isk, Decimal::new(81_48, 2)),
-					(Currency::Jpy, Decimal::new(133_73, 2)),
-					(Currency::Krw, Decimal::new(1398_59, 2)),
-					(Currency::Nok, Decimal::new(8_855, 3)),
-					(Currency::Nzd, Decimal::new(2_2229, 4)),
-					(Currency::Pln, Decimal::new(4_0712, 4)),
-					(Currency::Sek, Decimal::new(9_4696, 4)),
-					(Currency::Sgd, Decimal::new(1_9554, 4)),
-					(Currency::Usd, Decimal::new(1_1789, 4)),
-					(Currency::Zar, Decimal::new(6_9358, 4)),
-				]
-				.into_iter()
-				.collect()
-			)
-		);
+		// NOTE: `missing` is in the order of the columns of the ECB's CSV
+		assert_eq!(rates, &ExchangeRates {
+			date:    Some(*date),
+			missing: vec![
+				Currency::Bgn,
+				Currency::Ron,
+				Currency::Rub,
+				Currency::Try,
+				Currency::Brl,
+				Currency::Cny,
+				Currency::Idr,
+				Currency::Ils,
+				Currency::Inr,
+				Currency::Mxn,
+				Currency::Myr,
+				Currency::Php,
+				Currency::Thb,
+			],
+			rates:   [
+				(Currency::Aud, Decimal::new(1_91, 2)),
+				(Currency::Cad, Decimal::new(1_8004, 4)),
+				(Currency::Chf, Decimal::new(1_6168, 4)),
+				(Currency::Czk, Decimal::new(35_107, 3)),
+				(Currency::Dkk, Decimal::new(7_4501, 4)),
+				(Currency::Eur, 1.into()),
+				(Currency::Gbp, Decimal::new(0_7111, 4)),
+				(Currency::Hkd, Decimal::new(9_1332, 4)),
+				(Currency::Huf, Decimal::new(251_48, 2)),
+				(Currency::Isk, Decimal::new(81_48, 2)),
+				(Currency::Jpy, Decimal::new(133_73, 2)),
+				(Currency::Krw, Decimal::new(1398_59, 2)),
+				(Currency::Nok, Decimal::new(8_855, 3)),
+				(Currency::Nzd, Decimal::new(2_2229, 4)),
+				(Currency::Pln, Decimal::new(4_0712, 4)),
+				(Currency::Sek, Decimal::new(9_4696, 4)),
+				(Currency::Sgd, Decimal::new(1_9554, 4)),
+				(Currency::Usd, Decimal::new(1_1789, 4)),
+				(Currency::Zar, Decimal::new(6_9358, 4)),
+			]
+			.into_iter()
+			.collect(),
+		});
 
 		Ok(())
 	}
 
+	#[cfg(feature = "network")]
 	#[tokio::test]
+	async fn cached_in() -> Result<()>
+	{
+		let date = |day| NaiveDate::from_ymd_opt(2021, 6, day).unwrap();
+		let dir = cache_dir("cached_in")?;
+		let filepath = |date| HistoricalExchangeRates::filepath_in(&dir, date);
+
+		// NOTE: the cache is seeded for each day, so nothing is downloaded
+		let refreshed_csv = "Date,USD,JPY,\n2021-06-08,1.2182,133.44,\n";
+		fs::write(filepath(date(3)), SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV)?;
+		fs::write(filepath(date(8)), refreshed_csv)?;
+
+		let client = reqwest::Client::new();
+		let cell = OnceCell::new();
+		let last_check = StdOnceLock::new();
+		let from_ecb = |d| HistoricalExchangeRates::from_ecb_cached_at(&client, filepath(d));
+		let cached_in = |clock: &FixedClock| {
+			let (now, interval) = (clock.today(), Duration::days(1));
+			HistoricalExchangeRates::cached_in(&cell, &last_check, now, interval, from_ecb)
+		};
+
+		let mut clock = FixedClock(date(3));
+		let cached = cached_in(&clock).await?;
+		let expected = HistoricalExchangeRates::parse_csv(SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV)?;
+		assert_eq!(*cached.read().await, expected);
+
+		// the record is kept until the interval has passed
+		assert!(core::ptr::eq(cached_in(&clock).await?, cached));
+		assert_eq!(*cached.read().await, expected);
+
+		clock.0 = date(8);
+		assert!(core::ptr::eq(cached_in(&clock).await?, cached));
+		assert_eq!(*cached.read().await, HistoricalExchangeRates::parse_csv(refreshed_csv)?);
+
+		fs::remove_dir_all(dir)?;
+		Ok(())
+	}
+
+	#[cfg(feature = "network")]
+	#[tokio::test]
+	#[allow(clippy::zero_prefixed_literal, reason = "dates are zero-padded as in ISO 8601")]
 	async fn get() -> Result<()>
 	{
 		let mut after =
@@ -464,6 +1331,7 @@ mod tests
 		Ok(())
 	}
 
+	#[cfg(feature = "network")]
 	#[tokio::test]
 	async fn exchange() -> Result<()>
 	{
@@ -477,4 +1345,340 @@ mod tests
 		assert_eq!(value, Money::new(18_69, 2, Default::default()));
 		Ok(())
 	}
+
+	#[test]
+	fn missing() -> Result<()>
+	{
+		let csv = "Date,USD,JPY,BGN,CYP,\n\
+		           2021-06-04,N/A,133.63,,N/A,\n\
+		           2021-06-03,1.2187,133.81,1.9558,N/A,\n";
+		let history = HistoricalExchangeRates::parse_csv(csv)?;
+		let date = |day| NaiveDate::from_ymd_opt(2021, 6, day).unwrap();
+
+		// NOTE: CYP is not a supported `Currency`, so it is never reported as missing
+		let rates = &history[&date(4)];
+		assert_eq!(rates.missing(), [Currency::Usd, Currency::Bgn]);
+		assert_eq!(rates.get(&Currency::Usd, &Currency::Eur), None);
+		assert_eq!(rates.get(&Currency::Eur, &Currency::Jpy), Some(Decimal::new(133_63, 2)));
+
+		assert_eq!(history[&date(3)].missing(), []);
+		Ok(())
+	}
+
+	#[test]
+	fn parse_csv()
+	{
+		let truncated = "Date,USD,JPY,\n2021-06-04,1.2163,133.63,\n2021-06-03,1.2187,\n";
+		assert!(matches!(
+			HistoricalExchangeRates::parse_csv(truncated),
+			Err(Error::Decode { reason, .. }) if reason.starts_with("line 3 ")
+		));
+		assert!(matches!(
+			HistoricalExchangeRates::parse_csv_reader(Cursor::new(truncated)),
+			Err(Error::Decode { reason, .. }) if reason.starts_with("line 3 ")
+		));
+
+		assert!(matches!(
+			HistoricalExchangeRates::parse_csv(""),
+			Err(Error::Decode { reason, .. }) if reason.contains("headers")
+		));
+		assert!(matches!(
+			HistoricalExchangeRates::parse_csv("Date,USD,JPY,\n"),
+			Err(Error::Decode { reason, .. }) if reason.contains("data")
+		));
+	}
+
+	#[test]
+	fn parse_csv_trailing_comma() -> Result<()>
+	{
+		let with = HistoricalExchangeRates::parse_csv("Date,USD,JPY,\n2021-06-04,1.2163,133.63,")?;
+		let without = HistoricalExchangeRates::parse_csv("Date,USD,JPY\n2021-06-04,1.2163,133.63")?;
+		let mixed = HistoricalExchangeRates::parse_csv("Date,USD,JPY,\n2021-06-04,1.2163,133.63")?;
+
+		let date = NaiveDate::from_ymd_opt(2021, 6, 4).unwrap();
+		assert_eq!(with[&date].rates.len(), 3);
+		assert_eq!(without, with);
+		assert_eq!(mixed, with);
+
+		// an empty value in the last column is still a column
+		let empty = HistoricalExchangeRates::parse_csv("Date,USD,JPY,\n2021-06-04,1.2163,,\n")?;
+		assert_eq!(empty[&date].missing(), [Currency::Jpy]);
+
+		// …even when the headers have no trailing comma to remove
+		let empty = HistoricalExchangeRates::parse_csv("Date,USD,JPY\n2021-06-04,1.2163,\n")?;
+		assert_eq!(empty[&date].missing(), [Currency::Jpy]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn parse_csv_not_positive()
+	{
+		["0", "-1.2163"].into_iter().for_each(|rate| {
+			let csv = format!("Date,USD,JPY,\n2021-06-04,{rate},133.63,");
+			assert!(matches!(
+				HistoricalExchangeRates::parse_csv(&csv),
+				Err(Error::Decode { reason, .. }) if reason.contains("USD") && reason.contains(rate)
+			));
+		});
+
+		// unreported rates are still only missing
+		assert!(HistoricalExchangeRates::parse_csv("Date,USD,\n2021-06-04,N/A,").is_ok());
+	}
+
+	#[test]
+	fn parse_csv_reader() -> Result<()>
+	{
+		let reader = Cursor::new(SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV);
+		assert_eq!(
+			HistoricalExchangeRates::parse_csv_reader(reader)?,
+			HistoricalExchangeRates::parse_csv(SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV)?
+		);
+
+		assert!(HistoricalExchangeRates::parse_csv_reader(Cursor::new("")).is_err());
+		assert!(matches!(
+			HistoricalExchangeRates::parse_csv_reader(Cursor::new("Date,USD,JPY,\n")),
+			Err(Error::Decode { reason, .. }) if reason.contains("data")
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn range_from() -> Result<()>
+	{
+		let history = HistoricalExchangeRates::parse_csv(SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV)?;
+		let date = |day| NaiveDate::from_ymd_opt(2021, 6, day).unwrap();
+
+		let range = HistoricalExchangeRates::range_from(&history, date(2), date(4));
+		assert_eq!(
+			range.iter().map(|(d, _)| *d).collect::<Vec<_>>(),
+			[date(2), date(3), date(4)]
+		);
+		assert_eq!(range[1].1, history[&date(3)]);
+
+		// weekends have no entries
+		assert_eq!(HistoricalExchangeRates::range_from(&history, date(5), date(6)), []);
+		assert_eq!(HistoricalExchangeRates::range_from(&history, date(7), date(1)), []);
+
+		Ok(())
+	}
+
+	#[test]
+	fn series_from()
+	{
+		let date = |day| NaiveDate::from_ymd_opt(2021, 6, day).unwrap();
+		let history: HistoricalExchangeMap = [
+			(date(3), [(Currency::Usd, Decimal::new(1_25, 2))].into_iter().collect()),
+			(date(1), [(Currency::Usd, Decimal::new(1_20, 2))].into_iter().collect()),
+			(date(2), [(Currency::Jpy, Decimal::new(130, 0))].into_iter().collect()),
+		]
+		.into_iter()
+		.collect();
+
+		let series = |from, to| HistoricalExchangeRates::series_from(&history, from, to);
+		assert_eq!(series(Currency::Eur, Currency::Usd), [
+			(date(1), Decimal::new(1_20, 2)),
+			(date(3), Decimal::new(1_25, 2)),
+		]);
+		assert_eq!(series(Currency::Usd, Currency::Eur), [
+			(date(1), Decimal::ONE / Decimal::new(1_20, 2)),
+			(date(3), Decimal::ONE / Decimal::new(1_25, 2)),
+		]);
+		assert_eq!(series(Currency::Usd, Currency::Jpy), []);
+	}
+
+	#[cfg(feature = "network")]
+	#[tokio::test]
+	async fn from_ecb_cached_at() -> Result<()>
+	{
+		let date = |day| NaiveDate::from_ymd_opt(2021, 6, day).unwrap();
+		let dir = cache_dir("from_ecb_cached_at")?;
+		let filepath = |day| HistoricalExchangeRates::filepath_in(&dir, date(day));
+		let [stale, path, newer] = [3, 4, 7].map(filepath);
+		[&stale, &path, &newer]
+			.into_iter()
+			.try_for_each(|p| fs::write(p, SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV))?;
+
+		let client = reqwest::Client::new();
+		let history = HistoricalExchangeRates::from_ecb_cached_at(&client, path.clone()).await?;
+		assert_eq!(
+			history,
+			HistoricalExchangeRates::parse_csv(SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV)?
+		);
+
+		// records from before the one which was read are removed
+		assert!(!stale.exists());
+		assert!(newer.exists());
+
+		fs::remove_file(&path)?;
+
+		// NOTE: the mock proxy cannot tunnel to the ECB, so the download fails after reaching it
+		let (client, proxy) = request::mock_proxy(b"HTTP/1.1 403 Forbidden\r\n\r\n".to_vec());
+		assert!(HistoricalExchangeRates::from_ecb_cached_at(&client, path.clone()).await.is_err());
+		assert!(proxy.join().unwrap().starts_with("CONNECT www.ecb.europa.eu:443 "));
+		assert!(!path.exists());
+
+		fs::remove_dir_all(dir)?;
+		Ok(())
+	}
+
+	#[cfg(feature = "network")]
+	#[tokio::test]
+	async fn from_ecb_recent() -> Result<()>
+	{
+		let history = HistoricalExchangeRates::from_ecb_recent().await?;
+		let (first, _) = history.first_key_value().unwrap();
+		let (last, _) = history.last_key_value().unwrap();
+
+		let today = LocalClock.today();
+		assert!(today.signed_duration_since(*first) <= Duration::days(95));
+		assert!(today.signed_duration_since(*last) <= Duration::days(7));
+		assert!(history.len() > 50);
+
+		Ok(())
+	}
+
+	#[cfg(feature = "blocking")]
+	#[test]
+	fn cached_blocking_in() -> Result<()>
+	{
+		let date = |day| NaiveDate::from_ymd_opt(2021, 6, day).unwrap();
+		let dir = cache_dir("cached_blocking_in")?;
+		let filepath = |date| HistoricalExchangeRates::filepath_in(&dir, date);
+		let path = filepath(date(3));
+		fs::write(&path, SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV)?;
+
+		let cell = StdOnceLock::new();
+		let from_ecb = |d| HistoricalExchangeRates::from_ecb_blocking(&filepath(d));
+		let cached_blocking_in = |now| {
+			HistoricalExchangeRates::cached_blocking_in(&cell, now, Duration::days(1), from_ecb)
+		};
+
+		let cached = cached_blocking_in(date(3))?;
+		let expected = HistoricalExchangeRates::parse_csv(SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV)?;
+		assert_eq!(cached.read().unwrap().1, expected);
+
+		// NOTE: the parsed history is kept in memory, so repeated lookups neither re-read the file
+		//       cache nor download the history again.
+		fs::remove_file(&path)?;
+		assert!(core::ptr::eq(cached_blocking_in(date(3))?, cached));
+		assert_eq!(cached.read().unwrap().1, expected);
+		assert!(!path.exists());
+
+		fs::remove_dir_all(dir)?;
+		Ok(())
+	}
+
+	#[cfg(feature = "blocking")]
+	#[test]
+	fn get_blocking()
+	{
+		let date = NaiveDate::from_ymd_opt(1999, 1, 4)
+			.and_then(|d| d.and_hms_opt(0, 0, 0))
+			.and_then(|dt| dt.and_local_timezone(Local).earliest());
+
+		let rates = HistoricalExchangeRates::get_blocking(date).unwrap().unwrap();
+		assert_eq!(rates.rates.get(&Currency::Usd), Some(&Decimal::new(1_1789, 4)));
+		assert_eq!(HistoricalExchangeRates::get_blocking(date).unwrap(), Some(rates));
+	}
+
+	#[test]
+	fn get_ref_before() -> Result<()>
+	{
+		let history = HistoricalExchangeRates::parse_csv(SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV)?;
+		let date = |month, day| {
+			NaiveDate::from_ymd_opt(2021, month, day)
+				.and_then(|d| d.and_hms_opt(0, 0, 0))
+				.and_then(|dt| dt.and_local_timezone(Local).earliest())
+		};
+
+		let get = |d| {
+			HistoricalExchangeRates::get_ref_before(&history, d).and_then(ExchangeRates::date)
+		};
+		assert_eq!(get(date(6, 3)), NaiveDate::from_ymd_opt(2021, 6, 3));
+		assert_eq!(get(date(6, 6)), NaiveDate::from_ymd_opt(2021, 6, 4));
+
+		// never falls forward
+		assert_eq!(get(date(5, 31)), None);
+		assert!(HistoricalExchangeRates::get_ref_from(&history, date(5, 31)).is_some());
+
+		Ok(())
+	}
+
+	#[test]
+	fn interpolate_from() -> Result<()>
+	{
+		let history = HistoricalExchangeRates::parse_csv(SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV)?;
+		let date = |day| NaiveDate::from_ymd_opt(2021, 6, day).unwrap();
+		let interpolate = |d| {
+			HistoricalExchangeRates::interpolate_from(&history, d, Currency::Eur, Currency::Usd)
+		};
+
+		// saturday, between friday and monday
+		assert_eq!(
+			interpolate(date(5)),
+			Some(Decimal::new(1_2163, 4) + Decimal::new(11, 4) / Decimal::from(3))
+		);
+		assert_eq!(interpolate(date(4)), Some(Decimal::new(1_2163, 4)));
+		assert_eq!(interpolate(date(8)), None);
+		assert_eq!(interpolate(NaiveDate::from_ymd_opt(2021, 5, 31).unwrap()), None);
+
+		Ok(())
+	}
+
+	#[cfg(feature = "network")]
+	#[test]
+	fn needs_refresh()
+	{
+		let date = |day| NaiveDate::from_ymd_opt(2021, 6, day).unwrap();
+		let needs_refresh = |last_checked, now, days| {
+			super::needs_refresh(date(last_checked), date(now), Duration::days(days))
+		};
+
+		assert!(!needs_refresh(3, 3, 1));
+		assert!(needs_refresh(3, 4, 1));
+		assert!(needs_refresh(3, 7, 1));
+		assert!(!needs_refresh(4, 3, 1));
+
+		assert!(!needs_refresh(3, 4, 3));
+		assert!(needs_refresh(3, 6, 3));
+
+		assert!(needs_refresh(3, 3, 0));
+	}
+
+	#[test]
+	fn get_ref_from_at() -> Result<()>
+	{
+		let history = HistoricalExchangeRates::parse_csv(SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV)?;
+		let date = |month, day| NaiveDate::from_ymd_opt(2021, month, day).unwrap();
+		let get_ref = |date| HistoricalExchangeRates::get_ref_from_at(&history, date);
+
+		assert_eq!(get_ref(date(6, 3)), history.get(&date(6, 3)));
+
+		// weekends resolve to the friday before
+		assert_eq!(get_ref(date(6, 6)), history.get(&date(6, 4)));
+
+		// dates before the record resolve to its first date
+		assert_eq!(get_ref(date(5, 31)), history.get(&date(6, 1)));
+		Ok(())
+	}
+
+	#[cfg(feature = "futures")]
+	#[tokio::test]
+	async fn stream_from() -> Result<()>
+	{
+		let history = HistoricalExchangeRates::parse_csv(SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV)?;
+		let date = |day| NaiveDate::from_ymd_opt(2021, 6, day).unwrap();
+
+		let streamed: Vec<_> =
+			HistoricalExchangeRates::stream_from(&history, date(2), date(4)).collect().await;
+		assert_eq!(streamed, HistoricalExchangeRates::range_from(&history, date(2), date(4)));
+		assert_eq!(streamed.first().map(|(d, _)| *d), Some(date(2)));
+		assert_eq!(streamed.last().map(|(d, _)| *d), Some(date(4)));
+
+		let empty: Vec<_> =
+			HistoricalExchangeRates::stream_from(&history, date(4), date(2)).collect().await;
+		assert_eq!(empty, []);
+		Ok(())
+	}
 }
@@ -4,8 +4,26 @@
 //!
 //! # Features
 //!
+//! * `blocking` adds synchronous alternatives to functions which download exchange rates (e.g.
+//!   `ExchangeRates::new_blocking`). These can be used alongside the `async` functions. This
+//!   enables `network`.
+//! * `futures` adds `HistoricalExchangeRates::stream`, which yields the exchange rates of each day
+//!   in a range as a [`Stream`][stream]. This enables `history`.
+//! * `history` adds the ability to exchange using the rates from a specific date, and
+//!   `ExchangeRates::parse_all`.
+//! * `network` (enabled by default) adds every function which downloads exchange rates (e.g.
+//!   `ExchangeRates::new`). Without it, exchange rates must instead be parsed from an existing
+//!   source (e.g. `str::parse`, `ExchangeRates::from_json`, or
+//!   `HistoricalExchangeRates::parse_csv`).
 //! * `num-traits` adds support for the [num-traits](https://docs.rs/num-traits/) crate.
-//! * `serde` adds support for the [serde](https://serde.rs) crate.
+//! * `observer` adds `ExchangeRates::set_observer`, to be notified when exchange rates are read
+//!   from the cache or downloaded (e.g. to record metrics).
+//! * `serde` adds support for the [serde](https://serde.rs) crate, [`ExchangeRates::from_json`],
+//!   and the [`serde`](mod@serde) module.
+//! * `tokio` makes `history` use [tokio](https://tokio.rs)'s synchronization primitives, rather
+//!   than the runtime-agnostic ones from [async-lock](https://docs.rs/async-lock/).
+//! * `xml` adds `ExchangeRates::from_ecb_xml`, which downloads exchange rates from the ECB's XML
+//!   feed rather than its zipped CSV.
 //!
 //! # Re-exports
 //!
@@ -15,6 +33,7 @@
 //! [decimal]: https://docs.rs/rust_decimal/latest/rust_decimal/struct.Decimal.html
 //! [exchange]: https://docs.rs/money2/latest/money2/exchange/trait.Exchange.html
 //! [money]: https://docs.rs/money2/latest/money2/struct.Money.html
+//! [stream]: https://docs.rs/futures-core/latest/futures_core/stream/trait.Stream.html
 
 #![allow(clippy::drop_non_drop)]
 #![forbid(unsafe_code)]
@@ -82,22 +101,42 @@
 	clippy::wildcard_imports
 )]
 
+#[cfg(feature = "network")]
+mod cache;
+#[cfg(feature = "history")]
+mod clock;
+mod conversion_summary;
 mod currency;
 mod error;
 mod exchange;
 mod exchange_rates;
+mod exchanged;
+mod format_options;
 #[cfg(feature = "history")]
 mod historical_exchange_rates;
+mod macros;
 mod money;
+#[cfg(feature = "observer")]
+mod rate_fetch_observer;
+#[cfg(feature = "network")]
 mod request;
+#[cfg(feature = "serde")]
+pub mod serde;
 
+#[cfg(feature = "history")]
+pub use clock::{Clock, LocalClock};
+pub use conversion_summary::ConversionSummary;
 pub use currency::Currency;
 pub use error::{Error, Result};
-pub use exchange::Exchange;
+pub use exchange::{Exchange, ExchangeIteratorExt};
 pub use exchange_rates::ExchangeRates;
+pub use exchanged::Exchanged;
+pub use format_options::{FormatOptions, NegativeStyle};
 #[cfg(feature = "history")]
-pub use historical_exchange_rates::HistoricalExchangeRates;
+pub use historical_exchange_rates::{HistoricalExchangeMap, HistoricalExchangeRates};
 pub use money::Money;
+#[cfg(feature = "observer")]
+pub use rate_fetch_observer::RateFetchObserver;
 pub use rust_decimal::Decimal;
 
 #[cfg(test)]
@@ -107,3 +146,11 @@ pub(crate) const SAMPLE_EXCHANGE_RATES_CSV: &str =
 	 1.2187, 133.81, 1.9558, 25.448, 7.4365, 0.85955, 345.82, 4.4520, 4.9220, 10.1145, 1.0961, \
 	 146.30, 10.1501, 89.2163, 10.5650, 1.5792, 6.1894, 1.4710, 7.7910, 9.4551, 17420.91, 3.9598, \
 	 88.8755, 1357.75, 24.3300, 5.0241, 1.6915, 58.208, 1.6141, 37.938, 16.5218, ";
+
+#[cfg(all(test, feature = "history"))]
+pub(crate) const SAMPLE_HISTORICAL_EXCHANGE_RATES_CSV: &str = "Date,USD,JPY,BGN,CYP,GBP,\n\
+	 2021-06-07,1.2174,133.38,1.9558,N/A,0.85930,\n\
+	 2021-06-04,1.2163,133.63,1.9558,N/A,0.86035,\n\
+	 2021-06-03,1.2187,133.81,1.9558,N/A,0.85955,\n\
+	 2021-06-02,1.2212,133.99,1.9558,N/A,0.86518,\n\
+	 2021-06-01,1.2225,134.10,1.9558,N/A,0.86240,\n";
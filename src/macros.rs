@@ -0,0 +1,60 @@
+/// Create [`Money`](crate::Money) from a decimal literal and a [`Currency`](crate::Currency)
+/// code, e.g. `money!(20.00, USD)` rather than `Money::new(20_00, 2, Currency::Usd)`.
+///
+/// The `amount` keeps the scale it was written with (e.g. `20.00` has two decimal places), and
+/// the code is case-insensitive.
+///
+/// # Panics
+///
+/// * When the `amount` cannot be [parsed](core::str::FromStr) as a [`Decimal`](crate::Decimal).
+/// * When the code is not a supported [`Currency`](crate::Currency).
+///
+/// Neither can be checked when compiling without a procedural macro, so both are parsed when the
+/// macro is evaluated.
+///
+/// # Examples
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// use money2::{money, Currency, Money};
+///
+/// assert_eq!(money!(20.00, USD), Money::new(20_00, 2, Currency::Usd));
+/// assert_eq!(money!(-1750, jpy), Money::new(-1750, 0, Currency::Jpy));
+/// assert_eq!(money!(20.00, USD).to_string(), "20.00 USD");
+/// ```
+#[macro_export]
+macro_rules! money {
+	($amount:literal, $currency:ident) => {
+		$crate::Money::from_decimal(
+			<$crate::Decimal as ::core::str::FromStr>::from_str(stringify!($amount))
+				.expect(concat!("`", stringify!($amount), "` should be a decimal")),
+			<$crate::Currency as ::core::str::FromStr>::from_str(stringify!($currency))
+				.expect(concat!("`", stringify!($currency), "` should be a supported currency")),
+		)
+	};
+}
+
+#[cfg(test)]
+mod tests
+{
+	use pretty_assertions::assert_eq;
+
+	use crate::{Currency, Money};
+
+	#[test]
+	fn money()
+	{
+		assert_eq!(money!(20.00, USD), Money::new(20_00, 2, Currency::Usd));
+		assert_eq!(money!(20.00, usd), Money::new(20_00, 2, Currency::Usd));
+		assert_eq!(money!(-0.5, EUR), Money::new(-5, 1, Currency::Eur));
+		assert_eq!(money!(1_750, JPY), Money::new(1750, 0, Currency::Jpy));
+		assert_eq!(money!(20.00, USD).amount.scale(), 2);
+	}
+
+	#[test]
+	#[should_panic = "`USB` should be a supported currency"]
+	fn money_unsupported()
+	{
+		let _ = money!(20.00, USB);
+	}
+}
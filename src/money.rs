@@ -14,25 +14,48 @@ mod display;
 mod div;
 mod div_assign;
 mod exchange;
+mod from;
 mod from_str;
 mod mul;
 mod mul_assign;
+mod neg;
 mod rem;
 mod rem_assign;
 mod sub;
 mod sub_assign;
 mod try_from;
 
+use core::cmp::Ordering;
+use std::collections::HashMap;
+
+use rust_decimal::prelude::ToPrimitive;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{Currency, Decimal};
+use crate::{
+	Currency,
+	Decimal,
+	Error,
+	Exchange,
+	ExchangeRates,
+	Exchanged,
+	FormatOptions,
+	NegativeStyle,
+	Result,
+};
 
 /// An `amount` of [`Currency`].
 ///
 /// To find out how much the `amount` would be in another [`Currency`], use
 /// [`exchange`](crate::Exchange::exchange).
 ///
+/// # Ordering
+///
+/// The derived [`Ord`] compares the `amount` first, and then the `currency`. Thus, it is only
+/// meaningful for [`Money`] of the same [`Currency`] (e.g. `5 USD` is considered greater than
+/// `10 JPY`). Use [`Money::try_cmp`], [`Money::try_max`], or [`Money::try_min`] to ensure the
+/// currencies are the same.
+///
 /// # See also
 ///
 /// * [`Money::new`], for how to create [`Money`] when an [amount](Decimal) does not already exist.
@@ -49,6 +72,35 @@ pub struct Money
 
 impl Money
 {
+	/// Reduce this [`Money`] by `pct` percent (e.g. `10` for a 10% discount), in the same
+	/// [`Currency`].
+	///
+	/// The result is [rounded](Decimal::rescale) to the [minor units](Currency::minor_units) of
+	/// this [`Money`]'s [`Currency`].
+	///
+	/// # Panics
+	///
+	/// * When [`Money::percent`] does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// let price = Money::new(20_00, 2, Currency::Usd);
+	/// assert_eq!(price.apply_discount(Decimal::TEN), Money::new(18_00, 2, Currency::Usd));
+	///
+	/// let price = Money::new(9_99, 2, Currency::Usd);
+	/// assert_eq!(price.apply_discount(Decimal::new(15, 0)), Money::new(8_49, 2, Currency::Usd));
+	/// ```
+	pub fn apply_discount(&self, pct: Decimal) -> Self
+	{
+		let mut amount = self.amount - self.percent(pct).amount;
+		amount.rescale(self.currency.minor_units());
+		Self { amount, currency: self.currency }
+	}
+
 	/// Performs a checked (i.e. the currencies are the same, otherwise returning [`None`])
 	/// `operation` on this value and the `operand`.
 	fn checked(
@@ -128,6 +180,32 @@ impl Money
 		self.checked(Decimal::checked_mul, rhs)
 	}
 
+	/// Returns [`Some`] if negating this [`Money`] doesn't over/underflow, for parity with the
+	/// other `checked_*` methods.
+	///
+	/// Since the range of a [`Decimal`] is symmetric (i.e. [`Decimal::MIN`] is the negation of
+	/// [`Decimal::MAX`]), this is currently always [`Some`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// assert_eq!(
+	///   Money::new(20_00, 2, Currency::Usd).checked_neg(),
+	///   Some(Money::new(-20_00, 2, Currency::Usd))
+	/// );
+	/// assert_eq!(
+	///   Money::from_decimal(Decimal::MIN, Currency::Usd).checked_neg(),
+	///   Some(Money::from_decimal(Decimal::MAX, Currency::Usd))
+	/// );
+	/// ```
+	pub fn checked_neg(self) -> Option<Self>
+	{
+		Some(-self)
+	}
+
 	/// Returns [`Some`] if `rhs` is the same [`Currency`] and doesn't over/underflow.
 	///
 	/// # Examples
@@ -170,6 +248,340 @@ impl Money
 		self.checked(Decimal::checked_sub, rhs)
 	}
 
+	/// Compare the `amount` of this [`Money`] to zero. Unlike [`Money::try_cmp`], this never fails,
+	/// since zero is the same in every [`Currency`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use core::cmp::Ordering;
+	///
+	/// use money2::{Currency, Money};
+	///
+	/// assert_eq!(Money::new(-20_00, 2, Currency::Usd).cmp_zero(), Ordering::Less);
+	/// assert_eq!(Money::new(0, 0, Currency::Jpy).cmp_zero(), Ordering::Equal);
+	/// ```
+	pub fn cmp_zero(&self) -> Ordering
+	{
+		self.amount.cmp(&Decimal::ZERO)
+	}
+
+	/// [Exchange](crate::Exchange::exchange_mut) each of the `items` into the `to` [`Currency`]
+	/// in-place, skipping those which cannot be [exchanged](Money::try_exchange) rather than
+	/// panicking.
+	///
+	/// Returns the number of `items` which were skipped, and so are not in the `to` [`Currency`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, ExchangeRates, Money};
+	///
+	/// let rates: ExchangeRates = "Date, USD, \n03 June 2021, 1.2187, ".parse().unwrap();
+	/// let mut items = [
+	///   Money::new(20_00, 2, Currency::Usd),
+	///   Money::new(1750, 0, Currency::Jpy),
+	///   Money::new(5_00, 2, Currency::Eur),
+	/// ];
+	///
+	/// assert_eq!(Money::convert_slice(&mut items, Currency::Eur, &rates), 1);
+	/// assert_eq!(items, [
+	///   Money::new(16_41, 2, Currency::Eur),
+	///   Money::new(1750, 0, Currency::Jpy),
+	///   Money::new(5_00, 2, Currency::Eur),
+	/// ]);
+	/// ```
+	pub fn convert_slice(items: &mut [Self], to: Currency, rates: &ExchangeRates) -> usize
+	{
+		items.iter_mut().fold(0, |skipped, item| {
+			item.try_exchange(to, rates).map_or(skipped + 1, |exchanged| {
+				*item = exchanged;
+				skipped
+			})
+		})
+	}
+
+	/// Return an [`Err`] if this [`Money`] and the `other` do not have the
+	/// [same currency](Money::is_same_currency), so that it can be checked with `?` before
+	/// performing operations which panic otherwise (e.g. [`Add`](core::ops::Add)).
+	///
+	/// # Errors
+	///
+	/// * [`Error::CurrencyMismatch`] if the currencies are not the same.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use money2::{Currency, Money, Result};
+	///
+	/// fn total(a: Money, b: Money) -> Result<Money>
+	/// {
+	///   a.ensure_same_currency(&b)?;
+	///   Ok(a + b)
+	/// }
+	///
+	/// assert!(total(Money::new(5, 0, Currency::Usd), Money::new(5, 0, Currency::Usd)).is_ok());
+	/// assert!(total(Money::new(5, 0, Currency::Usd), Money::new(5, 0, Currency::Eur)).is_err());
+	/// ```
+	pub fn ensure_same_currency(&self, other: &Self) -> Result<()>
+	{
+		match self.is_same_currency(other)
+		{
+			false => Err(Error::CurrencyMismatch(self.currency, other.currency)),
+			_ => Ok(()),
+		}
+	}
+
+	/// Like [`Exchange::exchange`](crate::Exchange::exchange), except the result is not
+	/// [rounded](Decimal::rescale). This preserves precision when chaining several exchanges, so
+	/// that the result can be rounded once at the end.
+	///
+	/// # Panics
+	///
+	/// * When [`ExchangeRates::index`] does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, ExchangeRates, Money};
+	///
+	/// let rates: ExchangeRates = "Date, USD, \n03 June 2021, 1.2187, ".parse().unwrap();
+	/// let usd = Money::new(20_00, 2, Currency::Usd);
+	///
+	/// let mut eur = usd.exchange_exact(Currency::Eur, &rates);
+	/// assert!(eur.amount.scale() > 2);
+	///
+	/// eur.amount.rescale(2);
+	/// assert_eq!(eur, Money::new(16_41, 2, Currency::Eur));
+	/// ```
+	pub fn exchange_exact(self, currency: Currency, rates: &ExchangeRates) -> Self
+	{
+		match self.currency == currency
+		{
+			false =>
+			{
+				Self { amount: self.amount * rates.index(&self.currency..&currency), currency }
+			},
+			_ => self,
+		}
+	}
+
+	/// [Exchange](Exchange::exchange) this [`Money`] into each of the given `currencies`, returning
+	/// the results in the same order.
+	///
+	/// # Panics
+	///
+	/// * When [`Money::try_exchange`] would return [`Err`] for any of the `currencies`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, ExchangeRates, Money};
+	///
+	/// let rates: ExchangeRates = "Date, USD, \n03 June 2021, 1.2187, ".parse().unwrap();
+	/// let usd = Money::new(20_00, 2, Currency::Usd);
+	///
+	/// assert_eq!(usd.exchange_many(&[Currency::Eur, Currency::Usd], &rates), [
+	///   Money::new(16_41, 2, Currency::Eur),
+	///   usd,
+	/// ]);
+	/// ```
+	pub fn exchange_many(&self, currencies: &[Currency], rates: &ExchangeRates) -> Vec<Self>
+	{
+		currencies.iter().map(|currency| self.exchange(*currency, rates)).collect()
+	}
+
+	/// Same as [`Exchange::exchange`], except this [`Money`] is kept alongside the result (e.g. to
+	/// record the original amount in an audit log).
+	///
+	/// # Panics
+	///
+	/// * When [`Money::try_exchange`] would return [`Err`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, ExchangeRates, Money};
+	///
+	/// let rates: ExchangeRates = "Date, USD, \n03 June 2021, 1.2187, ".parse().unwrap();
+	/// let usd = Money::new(20_00, 2, Currency::Usd);
+	/// let exchanged = usd.exchange_tracked(Currency::Eur, &rates);
+	///
+	/// assert_eq!(exchanged.original, usd);
+	/// assert_eq!(exchanged.converted, Money::new(16_41, 2, Currency::Eur));
+	/// ```
+	pub fn exchange_tracked(self, currency: Currency, rates: &ExchangeRates) -> Exchanged<Self>
+	{
+		Exchanged { original: self, converted: self.exchange(currency, rates) }
+	}
+
+	/// Display this [`Money`] according to the given `options`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, FormatOptions, Money, NegativeStyle};
+	///
+	/// let debt = Money::new(-20_00, 2, Currency::Usd);
+	/// let parentheses =
+	///   FormatOptions { negative_style: NegativeStyle::Parentheses, symbol: false };
+	///
+	/// assert_eq!(debt.format_with(FormatOptions::default()), "-20.00 USD");
+	/// assert_eq!(debt.format_with(parentheses), "(20.00) USD");
+	/// assert_eq!(debt.format_with(FormatOptions { symbol: true, ..parentheses }), "($20.00)");
+	/// ```
+	pub fn format_with(&self, options: FormatOptions) -> String
+	{
+		let amount = self.amount.abs();
+		let magnitude = match options.symbol
+		{
+			false => amount.to_string(),
+			_ => format!("{}{amount}", self.currency.symbol()),
+		};
+
+		let negative = self.amount.is_sign_negative() && !self.amount.is_zero();
+		let signed = match (negative, options.negative_style)
+		{
+			(false, _) => magnitude,
+			(_, NegativeStyle::Minus) => format!("-{magnitude}"),
+			(_, NegativeStyle::Parentheses) => format!("({magnitude})"),
+		};
+
+		match options.symbol
+		{
+			false => format!("{signed} {}", self.currency),
+			_ => signed,
+		}
+	}
+
+	/// Create new [`Money`] from an existing `amount`.
+	///
+	/// # See also
+	///
+	/// * [`Money::new`], for when an `amount` does not already exist.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// let amount = Decimal::new(20_00, 2);
+	/// assert_eq!(Money::from_decimal(amount, Currency::Usd), Money::new(20_00, 2, Currency::Usd));
+	/// ```
+	pub const fn from_decimal(amount: Decimal, currency: Currency) -> Self
+	{
+		Self { amount, currency }
+	}
+
+	/// Create new [`Money`] from an integer number of the `currency`'s
+	/// [minor units](Currency::minor_units) (e.g. cents), as is expected by many payment APIs.
+	///
+	/// # See also
+	///
+	/// * [`Money::to_minor_units`], the inverse of this function.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Money};
+	///
+	/// let usd = Money::from_minor_units(20_00, Currency::Usd);
+	/// assert_eq!(usd, Money::new(20_00, 2, Currency::Usd));
+	///
+	/// let jpy = Money::from_minor_units(1750, Currency::Jpy);
+	/// assert_eq!(jpy, Money::new(1750, 0, Currency::Jpy));
+	/// ```
+	pub fn from_minor_units(units: i64, currency: Currency) -> Self
+	{
+		Self::new(units, currency.minor_units(), currency)
+	}
+
+	/// Sum the `items` of each [`Currency`] separately, without [exchanging](Exchange::exchange)
+	/// them. Unlike [`Add`](core::ops::Add), this does not panic when the `items` have different
+	/// currencies.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Money};
+	///
+	/// let subtotals = Money::group_sum(&[
+	///   Money::new(20_00, 2, Currency::Usd),
+	///   Money::new(5_00, 2, Currency::Eur),
+	///   Money::new(2_50, 2, Currency::Usd),
+	/// ]);
+	///
+	/// assert_eq!(subtotals[&Currency::Usd], Money::new(22_50, 2, Currency::Usd));
+	/// assert_eq!(subtotals[&Currency::Eur], Money::new(5_00, 2, Currency::Eur));
+	/// ```
+	pub fn group_sum(items: &[Self]) -> HashMap<Currency, Self>
+	{
+		items.iter().fold(HashMap::new(), |mut sums, item| {
+			sums.entry(item.currency).and_modify(|sum| sum.amount += item.amount).or_insert(*item);
+			sums
+		})
+	}
+
+	/// Get the rate of exchange implied by this [`Money`] and the `other`, assuming that both
+	/// represent the same value in their respective [`Currency`] (e.g. the two sides of a
+	/// transaction). This is the `amount` of this [`Money`] divided by the `amount` of the `other`.
+	///
+	/// The rate is in the same terms as [`ExchangeRates::get(&other.currency, &self.currency)`],
+	/// so it can be used to reconcile the two.
+	///
+	/// [`ExchangeRates::get(&other.currency, &self.currency)`]: ExchangeRates::get
+	///
+	/// # Panics
+	///
+	/// * When the `amount` of the `other` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// let usd = Money::new(120, 0, Currency::Usd);
+	/// let eur = Money::new(100, 0, Currency::Eur);
+	///
+	/// assert_eq!(usd.implied_rate(&eur), Decimal::new(1_2, 1));
+	/// ```
+	pub fn implied_rate(&self, other: &Self) -> Decimal
+	{
+		self.amount / other.amount
+	}
+
+	/// Whether this [`Money`] and the `other` have the same [`Currency`], such that they can be
+	/// compared or used in arithmetic together.
+	///
+	/// # See also
+	///
+	/// * [`Money::ensure_same_currency`], to return an [`Err`] instead.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use money2::{Currency, Money};
+	///
+	/// let a = Money::new(5, 0, Currency::Usd);
+	///
+	/// assert!(a.is_same_currency(&Money::new(10, 0, Currency::Usd)));
+	/// assert!(!a.is_same_currency(&Money::new(5, 0, Currency::Eur)));
+	/// ```
+	pub fn is_same_currency(&self, other: &Self) -> bool
+	{
+		self.currency == other.currency
+	}
+
 	/// Create new [`Money`].
 	///
 	/// # Examples
@@ -189,22 +601,932 @@ impl Money
 		Self { amount: Decimal::new(amount, decimal_places), currency }
 	}
 
-	/// Performs an unchecked (i.e. panicking) `operation` on this value and the `operand`.
+	/// Create new [`Money`] with an `amount` of one in the given `currency`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Money};
+	///
+	/// assert_eq!(Money::one(Currency::Usd), Money::new(1, 0, Currency::Usd));
+	/// ```
+	pub const fn one(currency: Currency) -> Self
+	{
+		Self::from_decimal(Decimal::ONE, currency)
+	}
+
+	/// Get `pct` percent (e.g. `15` for 15%) of this [`Money`], in the same [`Currency`].
+	///
+	/// The result is [rounded](Decimal::rescale) to the [minor units](Currency::minor_units) of
+	/// this [`Money`]'s [`Currency`].
 	///
 	/// # Panics
 	///
-	/// * If this currency and the `operand`'s currency are not the same.
-	/// * If `operation` does.
-	fn unchecked(self, operation: fn(Decimal, Decimal) -> Decimal, operand: Self) -> Self
+	/// * When [`Decimal::mul`](core::ops::Mul::mul) does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// let bill = Money::new(20_00, 2, Currency::Usd);
+	/// assert_eq!(bill.percent(Decimal::new(15, 0)), Money::new(3_00, 2, Currency::Usd));
+	///
+	/// let bill = Money::new(9_99, 2, Currency::Usd);
+	/// assert_eq!(bill.percent(Decimal::new(15, 0)), Money::new(1_50, 2, Currency::Usd));
+	/// ```
+	pub fn percent(&self, pct: Decimal) -> Self
 	{
-		match self.currency == operand.currency
-		{
-			false => panic!(
-				"Attempted to perform operation on {} and {}, which have differing currencies",
-				self, operand
-			),
+		let mut amount = self.amount * pct / Decimal::ONE_HUNDRED;
+		amount.rescale(self.currency.minor_units());
+		Self { amount, currency: self.currency }
+	}
 
-			_ => Self { amount: operation(self.amount, operand.amount), currency: self.currency },
-		}
+	/// Get the ratio of this [`Money`] to the `other` (e.g. to find what share of a budget has been
+	/// spent). Unlike [`Div`](core::ops::Div), the result is a dimensionless [`Decimal`] rather
+	/// than [`Money`], since dividing one amount of a [`Currency`] by another cancels it out.
+	///
+	/// # Errors
+	///
+	/// * When [`Money::ensure_same_currency`] does.
+	///
+	/// # Panics
+	///
+	/// * When [`Decimal::div`](core::ops::Div::div) does (e.g. the `other` is zero).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// let spent = Money::new(15_00, 2, Currency::Usd);
+	/// let budget = Money::new(60_00, 2, Currency::Usd);
+	///
+	/// assert_eq!(spent.ratio(&budget).unwrap(), Decimal::new(25, 2));
+	/// assert!(spent.ratio(&Money::new(60_00, 2, Currency::Eur)).is_err());
+	/// ```
+	pub fn ratio(&self, other: &Self) -> Result<Decimal>
+	{
+		self.ensure_same_currency(other).map(|()| self.amount / other.amount)
+	}
+
+	/// Round the amount of this [`Money`] to `dp` decimal places, regardless of the
+	/// [minor units](Currency::minor_units) of its [`Currency`].
+	///
+	/// # See also
+	///
+	/// * [`Decimal::round_dp`], for the rounding strategy that is used.
+	/// * [`Money::round_dp_mut`], to round in-place.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Money};
+	///
+	/// let money = Money::new(20_567, 3, Currency::Usd);
+	///
+	/// assert_eq!(money.round_dp(0), Money::new(21, 0, Currency::Usd));
+	/// assert_eq!(money.round_dp(2), Money::new(20_57, 2, Currency::Usd));
+	/// ```
+	pub fn round_dp(&self, dp: u32) -> Self
+	{
+		Self { amount: self.amount.round_dp(dp), currency: self.currency }
+	}
+
+	/// Like [`Money::round_dp`], but rounds this [`Money`] in-place.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Money};
+	///
+	/// let mut money = Money::new(20_567, 3, Currency::Usd);
+	/// money.round_dp_mut(2);
+	///
+	/// assert_eq!(money, Money::new(20_57, 2, Currency::Usd));
+	/// ```
+	pub fn round_dp_mut(&mut self, dp: u32)
+	{
+		*self = self.round_dp(dp);
+	}
+
+	/// Same as [`Add::add`](core::ops::Add::add), except the `amount` saturates at
+	/// [`Decimal::MAX`] or [`Decimal::MIN`] instead of overflowing.
+	///
+	/// # Panics
+	///
+	/// * If this currency and the `rhs`'s currency are not the same.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// let max = Money::from_decimal(Decimal::MAX, Currency::Usd);
+	/// assert_eq!(max.saturating_add(Money::new(1, 0, Currency::Usd)), max);
+	/// ```
+	pub fn saturating_add(self, rhs: Self) -> Self
+	{
+		self.unchecked(Decimal::saturating_add, rhs)
+	}
+
+	/// Same as [`Mul::mul`](core::ops::Mul::mul), except the `amount` saturates at
+	/// [`Decimal::MAX`] or [`Decimal::MIN`] instead of overflowing.
+	///
+	/// # Panics
+	///
+	/// * If this currency and the `rhs`'s currency are not the same.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// let max = Money::from_decimal(Decimal::MAX, Currency::Usd);
+	/// assert_eq!(max.saturating_mul(Money::new(2, 0, Currency::Usd)), max);
+	/// ```
+	pub fn saturating_mul(self, rhs: Self) -> Self
+	{
+		self.unchecked(Decimal::saturating_mul, rhs)
+	}
+
+	/// Same as [`Sub::sub`](core::ops::Sub::sub), except the `amount` saturates at
+	/// [`Decimal::MAX`] or [`Decimal::MIN`] instead of overflowing.
+	///
+	/// # Panics
+	///
+	/// * If this currency and the `rhs`'s currency are not the same.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// let min = Money::from_decimal(Decimal::MIN, Currency::Usd);
+	/// assert_eq!(min.saturating_sub(Money::new(1, 0, Currency::Usd)), min);
+	/// ```
+	pub fn saturating_sub(self, rhs: Self) -> Self
+	{
+		self.unchecked(Decimal::saturating_sub, rhs)
+	}
+
+	/// Same as [`Money::saturating_sub`], except the `amount` saturates at zero rather than going
+	/// negative (e.g. for a balance which cannot be overdrawn).
+	///
+	/// # Errors
+	///
+	/// * When [`Money::ensure_same_currency`] does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Money};
+	///
+	/// let balance = Money::new(20_00, 2, Currency::Usd);
+	///
+	/// assert_eq!(
+	///   balance.saturating_sub_at_zero(Money::new(5_00, 2, Currency::Usd)).unwrap(),
+	///   Money::new(15_00, 2, Currency::Usd),
+	/// );
+	/// assert_eq!(
+	///   balance.saturating_sub_at_zero(Money::new(25_00, 2, Currency::Usd)).unwrap().to_string(),
+	///   "0.00 USD",
+	/// );
+	/// assert!(balance.saturating_sub_at_zero(Money::new(5_00, 2, Currency::Eur)).is_err());
+	/// ```
+	pub fn saturating_sub_at_zero(self, rhs: Self) -> Result<Self>
+	{
+		self.ensure_same_currency(&rhs)?;
+
+		let difference = self.saturating_sub(rhs);
+		Ok(match difference.amount.is_sign_negative()
+		{
+			false => difference,
+			_ => Self::new(0, difference.amount.scale(), difference.currency),
+		})
+	}
+
+	/// Get the amount of this [`Money`], [rounded](Decimal::rescale) to the
+	/// [minor units](Currency::minor_units) of its [`Currency`]. The `amount` itself is unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// let money = Money::new(20_567, 3, Currency::Usd);
+	///
+	/// assert_eq!(money.scaled_amount(), Decimal::new(20_57, 2));
+	/// assert_eq!(money.amount, Decimal::new(20_567, 3));
+	/// assert_eq!(Money::new(20, 0, Currency::Usd).scaled_amount().to_string(), "20.00");
+	/// ```
+	pub fn scaled_amount(&self) -> Decimal
+	{
+		let mut amount = self.amount;
+		amount.rescale(self.currency.minor_units());
+		amount
+	}
+
+	/// Split this [`Money`] into its net amount and the amount of tax included in it, given the
+	/// tax `rate` (e.g. `0.21` for 21%).
+	///
+	/// The net amount is [rounded](Decimal::rescale) to the [minor units](Currency::minor_units) of
+	/// this [`Money`]'s [`Currency`], and the tax is the difference between this [`Money`] and the
+	/// net amount. Thus, the two always sum to this [`Money`].
+	///
+	/// # Panics
+	///
+	/// * When [`Decimal::div`](core::ops::Div::div) does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// let gross = Money::new(121_00, 2, Currency::Eur);
+	/// let (net, tax) = gross.split_tax(Decimal::new(21, 2));
+	///
+	/// assert_eq!(net, Money::new(100_00, 2, Currency::Eur));
+	/// assert_eq!(tax, Money::new(21_00, 2, Currency::Eur));
+	/// assert_eq!(net + tax, gross);
+	///
+	/// let (net, tax) = Money::new(10_00, 2, Currency::Eur).split_tax(Decimal::new(21, 2));
+	/// assert_eq!(net, Money::new(8_26, 2, Currency::Eur));
+	/// assert_eq!(tax, Money::new(1_74, 2, Currency::Eur));
+	/// ```
+	pub fn split_tax(&self, rate: Decimal) -> (Self, Self)
+	{
+		let mut net = self.amount / (Decimal::ONE + rate);
+		net.rescale(self.currency.minor_units());
+
+		(
+			Self { amount: net, currency: self.currency },
+			Self { amount: self.amount - net, currency: self.currency },
+		)
+	}
+
+	/// Multiply the amount of this [`Money`] by an integer `quantity` (e.g. the price of each item
+	/// on a line of an invoice).
+	///
+	/// The result is [rounded](Decimal::rescale) to the [minor units](Currency::minor_units) of
+	/// this [`Money`]'s [`Currency`].
+	///
+	/// # Panics
+	///
+	/// * When [`Decimal::mul`](core::ops::Mul::mul) does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Money};
+	///
+	/// let price = Money::new(4_99, 2, Currency::Usd);
+	/// assert_eq!(price.times(3), Money::new(14_97, 2, Currency::Usd));
+	/// ```
+	pub fn times(&self, quantity: u32) -> Self
+	{
+		let mut amount = self.amount * Decimal::from(quantity);
+		amount.rescale(self.currency.minor_units());
+		Self { amount, currency: self.currency }
+	}
+
+	/// [Exchange](crate::Exchange::exchange) this [`Money`] into the `base` [`Currency`] using
+	/// the `rates`.
+	///
+	/// # Panics
+	///
+	/// * When [`Exchange::exchange`](crate::Exchange::exchange) does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, ExchangeRates, Money};
+	///
+	/// let rates: ExchangeRates =
+	///   "Date, USD, JPY, \n03 June 2021, 1.2187, 133.81, ".parse().unwrap();
+	///
+	/// assert_eq!(
+	///   Money::new(20_00, 2, Currency::Usd).to_base(Currency::Jpy, &rates),
+	///   Money::new(2195_95, 2, Currency::Jpy)
+	/// );
+	/// ```
+	pub fn to_base(self, base: Currency, rates: &ExchangeRates) -> Self
+	{
+		self.exchange(base, rates)
+	}
+
+	/// [Exchange](crate::Exchange::exchange) this [`Money`] into [`Currency::Eur`] using the
+	/// `rates`.
+	///
+	/// # Panics
+	///
+	/// * When [`Exchange::exchange`](crate::Exchange::exchange) does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, ExchangeRates, Money};
+	///
+	/// let rates: ExchangeRates =
+	///   "Date, USD, JPY, \n03 June 2021, 1.2187, 133.81, ".parse().unwrap();
+	///
+	/// assert_eq!(
+	///   Money::new(20_00, 2, Currency::Usd).to_eur(&rates),
+	///   Money::new(16_41, 2, Currency::Eur)
+	/// );
+	/// assert_eq!(
+	///   Money::new(1750, 0, Currency::Jpy).to_eur(&rates),
+	///   Money::new(13_08, 2, Currency::Eur)
+	/// );
+	/// ```
+	pub fn to_eur(self, rates: &ExchangeRates) -> Self
+	{
+		self.to_base(Currency::Eur, rates)
+	}
+
+	/// Convert the `amount` of this [`Money`] into an [`f64`] (e.g. for a charting library).
+	///
+	/// This conversion is lossy: an [`f64`] cannot represent most decimal fractions exactly (e.g.
+	/// `0.1`), and has less precision than a [`Decimal`]. Thus, the result should only be used for
+	/// display, and never for further calculations. The [`Currency`] is also discarded.
+	///
+	/// Returns [`None`] if the `amount` cannot be represented as an [`f64`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Money};
+	///
+	/// assert_eq!(Money::new(20_50, 2, Currency::Usd).to_f64(), Some(20.5));
+	/// ```
+	pub fn to_f64(&self) -> Option<f64>
+	{
+		self.amount.to_f64()
+	}
+
+	/// Convert this [`Money`] into an integer number of its [`Currency`]'s
+	/// [minor units](Currency::minor_units) (e.g. cents), as is expected by many payment APIs.
+	///
+	/// # Errors
+	///
+	/// * [`Error::Decimal`] if the number of minor units does not fit in an [`i64`] (or even a
+	///   [`Decimal`]).
+	/// * [`Error::Decode`] if the amount has a fraction of a minor unit (e.g. `$0.001`).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Error, Money};
+	///
+	/// assert_eq!(Money::new(20_00, 2, Currency::Usd).to_minor_units().unwrap(), 20_00);
+	/// assert_eq!(Money::new(20, 0, Currency::Usd).to_minor_units().unwrap(), 20_00);
+	/// assert_eq!(Money::new(1750, 0, Currency::Jpy).to_minor_units().unwrap(), 1750);
+	///
+	/// assert!(matches!(
+	///   Money::new(i64::MAX, 0, Currency::Usd).to_minor_units(),
+	///   Err(Error::Decimal(_))
+	/// ));
+	/// assert!(matches!(
+	///   Money::new(20_001, 3, Currency::Usd).to_minor_units(),
+	///   Err(Error::Decode { .. })
+	/// ));
+	/// ```
+	pub fn to_minor_units(&self) -> Result<i64>
+	{
+		let units = self
+			.amount
+			.checked_mul(Decimal::from(10_i64.pow(self.currency.minor_units())))
+			.ok_or(match self.amount.is_sign_negative()
+			{
+				false => rust_decimal::Error::ExceedsMaximumPossibleValue,
+				_ => rust_decimal::Error::LessThanMinimumPossibleValue,
+			})?;
+
+		match units.fract().is_zero()
+		{
+			false => Err(Error::Decode {
+				context: format!("{self} into minor units"),
+				reason:  "the amount had a fraction of a minor unit".into(),
+			}),
+			_ => i64::try_from(units).map_err(Into::into),
+		}
+	}
+
+	/// Like [`Ord::clamp`], except returning an [`Err`] instead of comparing [`Money`] of different
+	/// currencies, or panicking when `low` is greater than `high`.
+	///
+	/// # Errors
+	///
+	/// * [`Error::CurrencyMismatch`] if the currencies of `self`, `low`, and `high` are not all the
+	///   same.
+	/// * [`Error::InvalidBounds`] if `low` is greater than `high`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Error, Money};
+	///
+	/// let low = Money::new(1_00, 2, Currency::Usd);
+	/// let high = Money::new(5_00, 2, Currency::Usd);
+	///
+	/// assert_eq!(Money::new(50, 2, Currency::Usd).try_clamp(low, high).unwrap(), low);
+	/// let mid = Money::new(2_50, 2, Currency::Usd);
+	/// assert_eq!(mid.try_clamp(low, high).unwrap(), mid);
+	///
+	/// assert_eq!(Money::new(9_00, 2, Currency::Usd).try_clamp(low, high).unwrap(), high);
+	/// assert!(matches!(
+	///   Money::new(2_50, 2, Currency::Jpy).try_clamp(low, high),
+	///   Err(Error::CurrencyMismatch(Currency::Jpy, Currency::Usd))
+	/// ));
+	/// ```
+	pub fn try_clamp(self, low: Self, high: Self) -> Result<Self>
+	{
+		if low.try_cmp(&high)? == Ordering::Greater
+		{
+			return Err(Error::InvalidBounds(low, high));
+		}
+
+		self.try_max(low)?.try_min(high)
+	}
+
+	/// Compare the amount of this [`Money`] to the `other`, if they have the same [`Currency`].
+	///
+	/// # Errors
+	///
+	/// * [`Error::CurrencyMismatch`] if the currencies are not the same.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use core::cmp::Ordering;
+	///
+	/// use money2::{Currency, Error, Money};
+	///
+	/// let a = Money::new(5, 0, Currency::Usd);
+	///
+	/// assert_eq!(a.try_cmp(&Money::new(10, 0, Currency::Usd)).unwrap(), Ordering::Less);
+	/// assert_eq!(a.try_cmp(&Money::new(5_00, 2, Currency::Usd)).unwrap(), Ordering::Equal);
+	/// assert!(matches!(
+	///   a.try_cmp(&Money::new(10, 0, Currency::Jpy)),
+	///   Err(Error::CurrencyMismatch(Currency::Usd, Currency::Jpy))
+	/// ));
+	/// ```
+	pub fn try_cmp(&self, other: &Self) -> Result<Ordering>
+	{
+		self.ensure_same_currency(other).map(|()| self.amount.cmp(&other.amount))
+	}
+
+	/// Like [`Exchange::exchange`](crate::Exchange::exchange), except returning an [`Err`] instead
+	/// of panicking when `rates` does not contain this [`Money`]'s [`Currency`] or the desired
+	/// `currency`.
+	///
+	/// The result will be [rounded](Decimal::rescale) to two decimal places.
+	///
+	/// # Errors
+	///
+	/// * [`Error::MissingRate`] if `rates` has no rate for this [`Money`]'s [`Currency`] or the
+	///   desired `currency`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Error, ExchangeRates, Money};
+	///
+	/// let rates: ExchangeRates = "Date, USD, \n03 June 2021, 1.2187, ".parse().unwrap();
+	/// let usd = Money::new(20_00, 2, Currency::Usd);
+	///
+	/// assert_eq!(
+	///   usd.try_exchange(Currency::Eur, &rates).unwrap(),
+	///   Money::new(16_41, 2, Currency::Eur)
+	/// );
+	/// assert!(matches!(
+	///   usd.try_exchange(Currency::Jpy, &rates),
+	///   Err(Error::MissingRate(Currency::Jpy))
+	/// ));
+	/// ```
+	pub fn try_exchange(self, currency: Currency, rates: &ExchangeRates) -> Result<Self>
+	{
+		// noop for same currency
+		if self.currency == currency
+		{
+			return Ok(self);
+		}
+
+		let mut exchanged = self.amount * rates.try_index(&self.currency..&currency)?;
+		exchanged.rescale(2);
+
+		Ok(Self { amount: exchanged, currency })
+	}
+
+	/// Like [`Ord::max`], except returning an [`Err`] instead of comparing [`Money`] of different
+	/// currencies.
+	///
+	/// # Errors
+	///
+	/// * When [`Money::try_cmp`] does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Error, Money};
+	///
+	/// let a = Money::new(5, 0, Currency::Usd);
+	/// let b = Money::new(10, 0, Currency::Usd);
+	///
+	/// assert_eq!(a.try_max(b).unwrap(), b);
+	/// assert_eq!(a.max(b), b);
+	/// assert!(matches!(
+	///   a.try_max(Money::new(10, 0, Currency::Jpy)),
+	///   Err(Error::CurrencyMismatch(Currency::Usd, Currency::Jpy))
+	/// ));
+	/// ```
+	pub fn try_max(self, other: Self) -> Result<Self>
+	{
+		self.try_cmp(&other).map(|ordering| match ordering
+		{
+			Ordering::Greater => self,
+			_ => other,
+		})
+	}
+
+	/// Like [`Ord::min`], except returning an [`Err`] instead of comparing [`Money`] of different
+	/// currencies.
+	///
+	/// # Errors
+	///
+	/// * When [`Money::try_cmp`] does.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Error, Money};
+	///
+	/// let a = Money::new(5, 0, Currency::Usd);
+	/// let b = Money::new(10, 0, Currency::Usd);
+	///
+	/// assert_eq!(a.try_min(b).unwrap(), a);
+	/// assert_eq!(a.min(b), a);
+	/// assert!(matches!(
+	///   a.try_min(Money::new(10, 0, Currency::Jpy)),
+	///   Err(Error::CurrencyMismatch(Currency::Usd, Currency::Jpy))
+	/// ));
+	/// ```
+	pub fn try_min(self, other: Self) -> Result<Self>
+	{
+		self.try_cmp(&other).map(|ordering| match ordering
+		{
+			Ordering::Greater => other,
+			_ => self,
+		})
+	}
+
+	/// Performs an unchecked (i.e. panicking) `operation` on this value and the `operand`.
+	///
+	/// # Panics
+	///
+	/// * If this currency and the `operand`'s currency are not the same.
+	/// * If `operation` does.
+	fn unchecked(self, operation: fn(Decimal, Decimal) -> Decimal, operand: Self) -> Self
+	{
+		match self.currency == operand.currency
+		{
+			false => panic!(
+				"Attempted to perform operation on {} and {}, which have differing currencies",
+				self, operand
+			),
+
+			_ => Self { amount: operation(self.amount, operand.amount), currency: self.currency },
+		}
+	}
+
+	/// Rescale the amount of this [`Money`] and the `other` [`Money`] to whichever of their
+	/// [scales](Decimal::scale) is greater (e.g. so that both are displayed with the same number of
+	/// decimal places in a report).
+	///
+	/// # See also
+	///
+	/// * [`Decimal::rescale`], for how the amounts are rescaled.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Money};
+	///
+	/// let (ten, fifty_cents) =
+	///   Money::new(10, 0, Currency::Usd).with_matching_scale(&Money::new(50, 2, Currency::Usd));
+	///
+	/// assert_eq!(ten.to_string(), "10.00 USD");
+	/// assert_eq!(fifty_cents.to_string(), "0.50 USD");
+	/// ```
+	pub fn with_matching_scale(self, other: &Self) -> (Self, Self)
+	{
+		let scale = self.amount.scale().max(other.amount.scale());
+		let rescale = |mut money: Self| {
+			money.amount.rescale(scale);
+			money
+		};
+
+		(rescale(self), rescale(*other))
+	}
+
+	/// Create new [`Money`] with an `amount` of zero in the given `currency` (e.g. to begin a sum).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Money};
+	///
+	/// let items = [Money::new(20_00, 2, Currency::Usd), Money::new(5_00, 2, Currency::Usd)];
+	/// let total = items.into_iter().fold(Money::zero(Currency::Usd), |sum, item| sum + item);
+	///
+	/// assert_eq!(total, Money::new(25_00, 2, Currency::Usd));
+	/// ```
+	pub const fn zero(currency: Currency) -> Self
+	{
+		Self::from_decimal(Decimal::ZERO, currency)
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping, reason = "digits are grouped by decimal place")]
+mod tests
+{
+	use core::cmp::Ordering;
+
+	use pretty_assertions::assert_eq;
+
+	use super::Money;
+	use crate::{
+		Currency,
+		Decimal,
+		Error,
+		ExchangeRates,
+		FormatOptions,
+		NegativeStyle,
+		Result,
+		SAMPLE_EXCHANGE_RATES_CSV,
+	};
+
+	#[test]
+	fn checked_neg()
+	{
+		let usd = |amount| Money::from_decimal(amount, Currency::Usd);
+
+		assert_eq!(usd(Decimal::new(20_00, 2)).checked_neg(), Some(usd(Decimal::new(-20_00, 2))));
+		assert_eq!(usd(Decimal::ZERO).checked_neg(), Some(usd(Decimal::ZERO)));
+		assert_eq!(usd(Decimal::MIN).checked_neg(), Some(usd(Decimal::MAX)));
+		assert_eq!(usd(Decimal::MAX).checked_neg(), Some(usd(Decimal::MIN)));
+	}
+
+	#[test]
+	fn cmp_zero()
+	{
+		assert_eq!(Money::new(20_00, 2, Currency::Usd).cmp_zero(), Ordering::Greater);
+		assert_eq!(Money::new(0, 2, Currency::Usd).cmp_zero(), Ordering::Equal);
+		assert_eq!((-Money::new(0, 2, Currency::Usd)).cmp_zero(), Ordering::Equal);
+		assert_eq!(Money::new(-1, 0, Currency::Jpy).cmp_zero(), Ordering::Less);
+	}
+
+	#[test]
+	fn ensure_same_currency()
+	{
+		let usd = Money::new(20_00, 2, Currency::Usd);
+		let eur = Money::new(20_00, 2, Currency::Eur);
+
+		assert!(usd.is_same_currency(&usd));
+		assert!(usd.ensure_same_currency(&Money::new(1, 0, Currency::Usd)).is_ok());
+
+		assert!(!usd.is_same_currency(&eur));
+		assert!(matches!(
+			usd.ensure_same_currency(&eur),
+			Err(Error::CurrencyMismatch(Currency::Usd, Currency::Eur))
+		));
+	}
+
+	#[test]
+	fn exchange_many()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		let usd = Money::new(20_00, 2, Currency::Usd);
+
+		assert_eq!(usd.exchange_many(&[Currency::Eur, Currency::Gbp, Currency::Jpy], &rates), [
+			Money::new(16_41, 2, Currency::Eur),
+			Money::new(14_11, 2, Currency::Gbp),
+			Money::new(2195_95, 2, Currency::Jpy),
+		]);
+		assert_eq!(usd.exchange_many(&[], &rates), []);
+	}
+
+	#[test]
+	fn exchange_tracked()
+	{
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		let usd = Money::new(20_00, 2, Currency::Usd);
+		let exchanged = usd.exchange_tracked(Currency::Jpy, &rates);
+
+		assert_eq!(exchanged.original, usd);
+		assert_eq!(exchanged.converted, Money::new(2195_95, 2, Currency::Jpy));
+		assert_eq!(usd.exchange_tracked(Currency::Usd, &rates).converted, usd);
+	}
+
+	#[test]
+	fn format_with()
+	{
+		let debt = Money::new(-20_00, 2, Currency::Usd);
+		let format =
+			|negative_style, symbol| debt.format_with(FormatOptions { negative_style, symbol });
+
+		assert_eq!(format(NegativeStyle::Minus, false), debt.to_string());
+		assert_eq!(format(NegativeStyle::Minus, false), "-20.00 USD");
+		assert_eq!(format(NegativeStyle::Minus, true), "-$20.00");
+		assert_eq!(format(NegativeStyle::Parentheses, false), "(20.00) USD");
+		assert_eq!(format(NegativeStyle::Parentheses, true), "($20.00)");
+
+		let options = FormatOptions { negative_style: NegativeStyle::Parentheses, symbol: true };
+		assert_eq!(Money::new(20_00, 2, Currency::Eur).format_with(options), "€20.00");
+		assert_eq!(Money::new(-0, 2, Currency::Eur).format_with(options), "€0.00");
+	}
+
+	#[test]
+	fn group_sum()
+	{
+		let sums = Money::group_sum(&[
+			Money::new(20_00, 2, Currency::Usd),
+			Money::new(5_00, 2, Currency::Eur),
+			Money::new(-2_50, 2, Currency::Usd),
+		]);
+
+		assert_eq!(sums.len(), 2);
+		assert_eq!(sums[&Currency::Usd], Money::new(17_50, 2, Currency::Usd));
+		assert_eq!(sums[&Currency::Eur], Money::new(5_00, 2, Currency::Eur));
+		assert!(Money::group_sum(&[]).is_empty());
+	}
+
+	#[test]
+	fn implied_rate()
+	{
+		let usd = Money::new(120, 0, Currency::Usd);
+		let eur = Money::new(100, 0, Currency::Eur);
+
+		assert_eq!(usd.implied_rate(&eur), Decimal::new(1_2, 1));
+		assert_eq!(eur.implied_rate(&usd), Decimal::ONE / Decimal::new(1_2, 1));
+
+		let rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		let rate = rates.index(&Currency::Eur..&Currency::Usd);
+		let eur = Money::new(100_00, 2, Currency::Eur);
+		let usd = Money::from_decimal(eur.amount * rate, Currency::Usd);
+		assert_eq!(usd.implied_rate(&eur), rate);
+	}
+
+	#[test]
+	fn minor_units() -> Result<()>
+	{
+		[Currency::Usd, Currency::Jpy].into_iter().try_for_each(|currency| -> Result<()> {
+			[i64::MIN, -1, 0, 1, i64::MAX].into_iter().try_for_each(|units| {
+				assert_eq!(Money::from_minor_units(units, currency).to_minor_units()?, units);
+				Ok(())
+			})
+		})?;
+
+		assert!(matches!(
+			Money::from_decimal(Decimal::MAX, Currency::Usd).to_minor_units(),
+			Err(Error::Decimal(rust_decimal::Error::ExceedsMaximumPossibleValue))
+		));
+		assert!(matches!(
+			Money::from_decimal(Decimal::MIN, Currency::Usd).to_minor_units(),
+			Err(Error::Decimal(rust_decimal::Error::LessThanMinimumPossibleValue))
+		));
+		assert!(matches!(
+			Money::from_decimal(Decimal::MAX, Currency::Jpy).to_minor_units(),
+			Err(Error::Decimal(_))
+		));
+
+		Ok(())
+	}
+
+	#[test]
+	fn ratio()
+	{
+		let spent = Money::new(15_00, 2, Currency::Usd);
+		let budget = Money::new(60_00, 2, Currency::Usd);
+
+		assert_eq!(spent.ratio(&budget).unwrap(), Decimal::new(25, 2));
+		assert_eq!(budget.ratio(&spent).unwrap(), Decimal::new(4, 0));
+		assert_eq!((spent / budget).amount, spent.ratio(&budget).unwrap());
+		assert!(matches!(
+			spent.ratio(&Money::new(60_00, 2, Currency::Eur)),
+			Err(Error::CurrencyMismatch(Currency::Usd, Currency::Eur))
+		));
+	}
+
+	#[test]
+	fn saturating()
+	{
+		let max = Money::from_decimal(Decimal::MAX, Currency::Usd);
+		let min = Money::from_decimal(Decimal::MIN, Currency::Usd);
+		let one = Money::new(1, 0, Currency::Usd);
+		let two = Money::new(2, 0, Currency::Usd);
+
+		assert_eq!(max.saturating_add(one), max);
+		assert_eq!(min.saturating_add(-one), min);
+		assert_eq!((max - one).saturating_add(one), max);
+		assert_eq!(one.saturating_add(one), two);
+
+		assert_eq!(min.saturating_sub(one), min);
+		assert_eq!(max.saturating_sub(-one), max);
+		assert_eq!(two.saturating_sub(one), one);
+
+		assert_eq!(max.saturating_mul(two), max);
+		assert_eq!(max.saturating_mul(-two), min);
+		assert_eq!(one.saturating_mul(two), two);
+	}
+
+	#[test]
+	#[should_panic = "differing currencies"]
+	fn saturating_mismatch()
+	{
+		let _ = Money::new(1, 0, Currency::Usd).saturating_add(Money::new(1, 0, Currency::Eur));
+	}
+
+	#[test]
+	fn saturating_sub_at_zero() -> Result<()>
+	{
+		let balance = Money::new(20_00, 2, Currency::Usd);
+		let sub = |amount| balance.saturating_sub_at_zero(Money::new(amount, 2, Currency::Usd));
+
+		assert_eq!(sub(5_00)?, Money::new(15_00, 2, Currency::Usd));
+		assert_eq!(sub(20_00)?, Money::new(0, 2, Currency::Usd));
+		assert_eq!(sub(25_00)?, Money::new(0, 2, Currency::Usd));
+		assert_eq!(sub(25_00)?.to_string(), "0.00 USD");
+		assert_eq!(sub(-5_00)?, Money::new(25_00, 2, Currency::Usd));
+
+		assert!(matches!(
+			balance.saturating_sub_at_zero(Money::new(5_00, 2, Currency::Eur)),
+			Err(Error::CurrencyMismatch(Currency::Usd, Currency::Eur))
+		));
+
+		Ok(())
+	}
+
+	#[test]
+	fn times()
+	{
+		let price = Money::new(4_99, 2, Currency::Usd);
+
+		assert_eq!(price.times(3), Money::new(14_97, 2, Currency::Usd));
+		assert_eq!(price.times(3).to_string(), "14.97 USD");
+		assert_eq!(price.times(0), Money::new(0, 2, Currency::Usd));
+		assert_eq!(Money::new(1_005, 3, Currency::Jpy).times(2).to_string(), "2 JPY");
+	}
+
+	#[test]
+	fn to_f64()
+	{
+		assert_eq!(Money::new(20_00, 2, Currency::Usd).to_f64(), Some(20.0));
+		assert_eq!(Money::new(-1750, 0, Currency::Jpy).to_f64(), Some(-1750.0));
+		assert_eq!(Money::new(0, 2, Currency::Eur).to_f64(), Some(0.0));
+
+		// NOTE: the cent is lost, since an `f64` is not precise enough
+		let lossy = Money::new(1_000_000_000_000_000_01, 2, Currency::Usd);
+		assert_eq!(lossy.to_f64(), Some(1_000_000_000_000_000.0));
+	}
+
+	#[test]
+	fn with_matching_scale()
+	{
+		let ten = Money::new(10, 0, Currency::Usd);
+		let fifty_cents = Money::new(50, 2, Currency::Usd);
+
+		let (lhs, rhs) = ten.with_matching_scale(&fifty_cents);
+		assert_eq!((lhs, rhs), (ten, fifty_cents));
+		assert_eq!((lhs.amount.scale(), rhs.amount.scale()), (2, 2));
+		assert_eq!((lhs + rhs).to_string(), "10.50 USD");
+
+		let (lhs, rhs) = fifty_cents.with_matching_scale(&Money::new(1_125, 3, Currency::Eur));
+		assert_eq!(lhs.to_string(), "0.500 USD");
+		assert_eq!(rhs.to_string(), "1.125 EUR");
 	}
 }
@@ -1,6 +1,7 @@
 use core::ops::Div;
 
 use super::Money;
+use crate::Decimal;
 
 impl Div for Money
 {
@@ -14,6 +15,7 @@ impl Div for Money
 	/// # See also
 	///
 	/// * [`Div::div`]
+	/// * [`Money::ratio`], for a dimensionless [`Decimal`] rather than [`Money`].
 	///
 	/// # Examples
 	///
@@ -37,3 +39,34 @@ impl Div for Money
 		self.unchecked(Div::div, rhs)
 	}
 }
+
+impl Div<Decimal> for Money
+{
+	type Output = Self;
+
+	/// Scale the amount of this [`Money`] by the inverse of some factor (e.g. splitting a bill).
+	///
+	/// # Panics
+	///
+	/// * When [`Decimal::div`] does.
+	///
+	/// # See also
+	///
+	/// * [`Div::div`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// assert_eq!(
+	///   Money::new(10, 0, Currency::Usd) / Decimal::new(4, 0),
+	///   Money::new(2_50, 2, Currency::Usd)
+	/// );
+	/// ```
+	fn div(self, rhs: Decimal) -> Self::Output
+	{
+		Self { amount: self.amount / rhs, currency: self.currency }
+	}
+}
@@ -1,6 +1,7 @@
 use core::ops::{Div, DivAssign};
 
 use super::Money;
+use crate::Decimal;
 
 impl DivAssign for Money
 {
@@ -35,3 +36,29 @@ impl DivAssign for Money
 		*self = self.div(rhs);
 	}
 }
+
+impl DivAssign<Decimal> for Money
+{
+	/// # Panics
+	///
+	/// * When [`Money::div`] does.
+	///
+	/// # See also
+	///
+	/// * [`DivAssign::div_assign`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// let mut foo = Money::new(10, 0, Currency::Usd);
+	/// foo /= Decimal::new(4, 0);
+	/// assert_eq!(foo, Money::new(2_50, 2, Currency::Usd));
+	/// ```
+	fn div_assign(&mut self, rhs: Decimal)
+	{
+		*self = self.div(rhs);
+	}
+}
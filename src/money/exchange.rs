@@ -5,32 +5,27 @@ impl Exchange for Money
 {
 	/// The result will be [rounded](crate::Decimal::rescale) to two decimal places.
 	///
+	/// # Panics
+	///
+	/// * When [`Money::try_exchange`] would return [`Err`].
+	///
 	/// # See also
 	///
 	/// * [`Exchange::exchange_mut`]
 	fn exchange_mut(&mut self, currency: Currency, rates: &ExchangeRates)
 	{
-		// noop for same currency
-		if self.currency == currency
-		{
-			return;
-		}
-
-		let mut exchanged = self.amount * rates.index(&self.currency..&currency);
-		exchanged.rescale(2);
-
-		self.amount = exchanged;
-		self.currency = currency;
+		*self = self.try_exchange(currency, rates).unwrap_or_else(|e| panic!("{e} in {rates:?}"));
 	}
 }
 
 #[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping, reason = "digits are grouped by decimal place")]
 mod tests
 {
 	use pretty_assertions::assert_eq;
 
 	use super::{Currency, ExchangeRates, Money};
-	use crate::{Exchange, SAMPLE_EXCHANGE_RATES_CSV};
+	use crate::{Error, Exchange, SAMPLE_EXCHANGE_RATES_CSV};
 
 	#[test]
 	fn exchange()
@@ -46,4 +41,39 @@ mod tests
 		let usd_to_jpy_to_usd = usd_to_jpy.exchange(Currency::Usd, &exchange_rates);
 		assert_eq!(usd, usd_to_jpy_to_usd);
 	}
+
+	#[test]
+	fn exchange_exact()
+	{
+		let exchange_rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		let usd = Money::new(20_00, 2, Currency::Usd);
+
+		let rounded = usd
+			.exchange(Currency::Jpy, &exchange_rates)
+			.exchange(Currency::Gbp, &exchange_rates)
+			.exchange(Currency::Usd, &exchange_rates);
+
+		let mut exact = usd
+			.exchange_exact(Currency::Jpy, &exchange_rates)
+			.exchange_exact(Currency::Gbp, &exchange_rates)
+			.exchange_exact(Currency::Usd, &exchange_rates);
+		exact.amount.rescale(2);
+
+		assert_eq!(exact, usd);
+		assert_eq!(rounded, Money::new(20_01, 2, Currency::Usd));
+	}
+
+	#[test]
+	fn try_exchange()
+	{
+		let mut exchange_rates = SAMPLE_EXCHANGE_RATES_CSV.parse::<ExchangeRates>().unwrap();
+		exchange_rates.rates.remove(&Currency::Usd);
+
+		let usd = Money::new(20_00, 2, Currency::Usd);
+		assert!(matches!(
+			usd.try_exchange(Currency::Jpy, &exchange_rates),
+			Err(Error::MissingRate(Currency::Usd))
+		));
+		assert_eq!(usd.try_exchange(Currency::Usd, &exchange_rates).unwrap(), usd);
+	}
 }
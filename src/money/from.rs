@@ -0,0 +1,44 @@
+use super::Money;
+use crate::{Currency, Decimal};
+
+impl From<(Decimal, Currency)> for Money
+{
+	/// # See also
+	///
+	/// * [`Money::from_decimal`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// let money: Money = (Decimal::new(20_00, 2), Currency::Usd).into();
+	/// assert_eq!(money, Money::new(20_00, 2, Currency::Usd));
+	/// ```
+	fn from((amount, currency): (Decimal, Currency)) -> Self
+	{
+		Self::from_decimal(amount, currency)
+	}
+}
+
+impl From<(i64, u32, Currency)> for Money
+{
+	/// # See also
+	///
+	/// * [`Money::new`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Money};
+	///
+	/// let money: Money = (20_00, 2, Currency::Usd).into();
+	/// assert_eq!(money, Money::new(20_00, 2, Currency::Usd));
+	/// ```
+	fn from((amount, decimal_places, currency): (i64, u32, Currency)) -> Self
+	{
+		Self::new(amount, decimal_places, currency)
+	}
+}
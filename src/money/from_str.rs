@@ -1,34 +1,272 @@
 use core::str::FromStr;
 
+use strum::IntoEnumIterator;
+
 use super::Money;
-use crate::{Error, Result};
+use crate::{Currency, Error, Result};
+
+/// Characters (other than `,` and `.`) which may be used to group the digits of an amount. `_` is
+/// included for amounts written like Rust literals (e.g. `1_000.50`).
+const GROUP_SEPARATORS: [char; 5] = [' ', '\'', '_', '\u{a0}', '\u{202f}'];
+
+/// Normalize the `amount` of a [`Money`] literal such that it can be parsed into a
+/// [`Decimal`](crate::Decimal), by removing thousands separators and using `.` as the decimal
+/// separator.
+///
+/// Returns [`None`] if the decimal separator is ambiguous (e.g. `1,234.5,6`).
+fn normalize_amount(amount: &str) -> Option<String>
+{
+	let amount: String = amount.chars().filter(|c| !GROUP_SEPARATORS.contains(c)).collect();
+	let (decimal, group) = match (amount.rfind('.'), amount.rfind(','))
+	{
+		(Some(period), Some(comma)) if period > comma => ('.', ','),
+		(Some(_), Some(_)) => (',', '.'),
+
+		// NOTE: a lone comma followed by three digits is most likely a thousands separator
+		(None, Some(comma)) if amount.matches(',').count() == 1 && amount.len() - comma != 4 =>
+		{
+			(',', '.')
+		},
+		(Some(_), None) if amount.matches('.').count() > 1 => (',', '.'),
+		_ => ('.', ','),
+	};
+
+	match amount.matches(decimal).count() > 1
+	{
+		false => Some(
+			amount
+				.chars()
+				.filter(|c| *c != group)
+				.map(|c| match c == decimal
+				{
+					false => c,
+					_ => '.',
+				})
+				.collect(),
+		),
+		_ => None,
+	}
+}
+
+/// Split a currency code (e.g. `USD`) from either side of the `literal`, returning the rest of
+/// the `literal` and the [`Currency`] (if a code was found).
+///
+/// The code is usually separated from the amount by a space, but a three-letter code may also be
+/// written directly beside it (e.g. `20.00USD`).
+fn split_code(literal: &str) -> Result<(&str, Option<Currency>)>
+{
+	let is_code = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphabetic());
+
+	if let Some((amount, code)) = literal.rsplit_once(' ').filter(|(_, code)| is_code(code))
+	{
+		return code.parse().map(|currency| (amount, Some(currency)));
+	}
+
+	if let Some((code, amount)) = literal.split_once(' ').filter(|(code, _)| is_code(code))
+	{
+		return code.parse().map(|currency| (amount, Some(currency)));
+	}
+
+	// NOTE: the code must not be part of a longer word (e.g. `20.00XUSD`), and there must be an
+	//       amount beside it (e.g. not just `USD`).
+	let is_letter = |c: char| c.is_ascii_alphabetic();
+	let prefix = literal.get(..3).zip(literal.get(3..)).filter(|(code, amount)| {
+		is_code(code) && !(amount.is_empty() || amount.starts_with(is_letter))
+	});
+	let suffix = literal
+		.len()
+		.checked_sub(3)
+		.and_then(|i| literal.get(..i).zip(literal.get(i..)))
+		.filter(|(amount, code)| {
+			is_code(code) && !(amount.is_empty() || amount.ends_with(is_letter))
+		});
+
+	match (prefix, suffix)
+	{
+		(Some(_), Some(_)) => Err(Error::Decode {
+			context: format!(r#""{literal}" into money"#),
+			reason:  "there was a currency code on both sides of the amount".into(),
+		}),
+		(Some((code, amount)), None) | (None, Some((amount, code))) =>
+		{
+			code.parse().map(|currency| (amount, Some(currency)))
+		},
+		(None, None) => Ok((literal, None)),
+	}
+}
+
+/// Split a [currency symbol](Currency::symbol) (e.g. `$`) from either side of the `literal`,
+/// returning the rest of the `literal` and the [`Currency`] (if a symbol was found).
+///
+/// When more than one symbol matches (e.g. `$` and `CA$`), the longest one is used.
+fn split_symbol(literal: &str) -> (&str, Option<Currency>)
+{
+	Currency::iter()
+		.filter_map(|currency| {
+			let symbol = currency.symbol();
+			literal
+				.strip_prefix(symbol)
+				.or_else(|| literal.strip_suffix(symbol))
+				.map(|amount| (amount.trim(), currency, symbol.len()))
+		})
+		.max_by_key(|(.., len)| *len)
+		.map_or((literal, None), |(amount, currency, _)| (amount, Some(currency)))
+}
 
 impl FromStr for Money
 {
 	type Err = Error;
 
+	/// Parse [`Money`] from a string such as `20.00 USD`.
+	///
+	/// The currency may be written as a code or a [symbol](Currency::symbol), on either side of
+	/// the amount. The amount may group its digits using `,`, `.`, `'`, `_`, or spaces, and may use
+	/// either `.` or `,` as its decimal separator. A negative amount may be written with a `-`
+	/// before the amount or currency symbol. Surrounding whitespace is ignored, and a three-letter
+	/// code need not be separated from the amount by a space (e.g. `20.00USD`).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Money};
+	///
+	/// let money = Money::new(1234_56, 2, Currency::Usd);
+	///
+	/// assert_eq!("1234.56 USD".parse::<Money>().unwrap(), money);
+	/// assert_eq!("USD 1,234.56".parse::<Money>().unwrap(), money);
+	/// assert_eq!("$1,234.56".parse::<Money>().unwrap(), money);
+	/// assert_eq!("1 234,56 USD".parse::<Money>().unwrap(), money);
+	/// assert_eq!(" -$1,234.56 ".parse::<Money>().unwrap(), -money);
+	/// assert_eq!("1234.56USD".parse::<Money>().unwrap(), money);
+	/// ```
 	fn from_str(s: &str) -> Result<Self>
 	{
-		let new_error = |field: &str| -> Error {
-			Error::Decode {
-				context: format!(r#""{s}" into money"#),
-				reason:  format!("there was no {field}"),
-			}
+		let new_error = |reason: String| -> Error {
+			Error::Decode { context: format!(r#""{s}" into money"#), reason }
 		};
 
-		// {{{
-		let mut split = s.split(' ');
+		let (literal, code) = split_code(s.trim())?;
 
-		let amount = {
-			let literal = split.next().ok_or_else(|| new_error("amount"))?;
-			literal.parse()?
-		};
+		// NOTE: the sign may come before a currency symbol (e.g. `-$20`)
+		let (sign, literal) =
+			literal.strip_prefix('-').map_or(("", literal), |l| ("-", l.trim_start()));
+		let (literal, symbol) = split_symbol(literal);
 
-		let currency = split.next().ok_or_else(|| new_error("currency")).and_then(str::parse)?;
+		let currency = match (code, symbol)
+		{
+			(Some(c), Some(s)) if c != s => Err(new_error(format!(
+				"the currency code {c} does not match the currency symbol {}",
+				s.symbol()
+			))),
+			(Some(c), _) | (_, Some(c)) => Ok(c),
+			(None, None) => Err(new_error("there was no currency".into())),
+		}?;
 
-		drop(split);
-		// }}}
+		let amount = match literal.is_empty()
+		{
+			false => normalize_amount(&format!("{sign}{literal}"))
+				.ok_or_else(|| new_error("the decimal separator was ambiguous".into()))?
+				.parse()?,
+			_ => return Err(new_error("there was no amount".into())),
+		};
 
 		Ok(Self { amount, currency })
 	}
 }
+
+#[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping, reason = "digits are grouped by decimal place")]
+mod tests
+{
+	use pretty_assertions::assert_eq;
+
+	use super::Money;
+	use crate::{Currency, Error};
+
+	#[test]
+	fn from_str()
+	{
+		let usd = Money::new(1234_56, 2, Currency::Usd);
+		let eur = Money::new(1234_56, 2, Currency::Eur);
+
+		// strict
+		assert_eq!("1234.56 USD".parse::<Money>().unwrap(), usd);
+
+		// symbol-prefixed
+		assert_eq!("$1,234.56".parse::<Money>().unwrap(), usd);
+		assert_eq!("€ 1.234,56".parse::<Money>().unwrap(), eur);
+		assert_eq!("CA$20".parse::<Money>().unwrap(), Money::new(20, 0, Currency::Cad));
+
+		// symbol-suffixed
+		assert_eq!("1.234,56 €".parse::<Money>().unwrap(), eur);
+
+		// comma-grouped
+		assert_eq!(
+			"1,234,567 JPY".parse::<Money>().unwrap(),
+			Money::new(1234567, 0, Currency::Jpy)
+		);
+		assert_eq!("1 234,56 EUR".parse::<Money>().unwrap(), eur);
+		assert_eq!("1'234.56 USD".parse::<Money>().unwrap(), usd);
+
+		// code-first
+		assert_eq!("USD 1234.56".parse::<Money>().unwrap(), usd);
+		assert_eq!("EUR 1 234,56".parse::<Money>().unwrap(), eur);
+
+		// ambiguous mixed separators
+		assert!(matches!("1,234.56,7 USD".parse::<Money>(), Err(Error::Decode { .. })));
+		assert!(matches!("1.234,5.6 EUR".parse::<Money>(), Err(Error::Decode { .. })));
+
+		// mismatched code and symbol
+		assert!(matches!("$20.00 EUR".parse::<Money>(), Err(Error::Decode { .. })));
+
+		// whitespace
+		assert_eq!(" 1234.56 USD ".parse::<Money>().unwrap(), usd);
+		assert_eq!("\t1234.56 USD\n".parse::<Money>().unwrap(), usd);
+
+		// negative
+		assert_eq!("-1234.56 USD".parse::<Money>().unwrap(), -usd);
+		assert_eq!(" -1234.56 USD ".parse::<Money>().unwrap(), -usd);
+		assert_eq!("- 1234.56 USD".parse::<Money>().unwrap(), -usd);
+		assert_eq!("USD -1234.56".parse::<Money>().unwrap(), -usd);
+		assert_eq!("-$1,234.56".parse::<Money>().unwrap(), -usd);
+		assert_eq!("-1.234,56 €".parse::<Money>().unwrap(), -eur);
+		assert!("--1234.56 USD".parse::<Money>().is_err());
+
+		// malformed
+		assert!(matches!("   ".parse::<Money>(), Err(Error::Decode { .. })));
+		assert!(matches!("- USD".parse::<Money>(), Err(Error::Decode { .. })));
+		assert!("twenty USD".parse::<Money>().is_err());
+
+		// unseparated code
+		let twenty = Money::new(20_00, 2, Currency::Usd);
+		assert_eq!("20.00USD".parse::<Money>().unwrap(), twenty);
+		assert_eq!("20.00 USD".parse::<Money>().unwrap(), twenty);
+		assert_eq!("USD20.00".parse::<Money>().unwrap(), twenty);
+		assert_eq!("-20.00USD".parse::<Money>().unwrap(), -twenty);
+		assert_eq!("1.234,56EUR".parse::<Money>().unwrap(), eur);
+		assert!(matches!("USD20.00EUR".parse::<Money>(), Err(Error::Decode { .. })));
+		assert!(matches!("20.00XUSD".parse::<Money>(), Err(Error::Decode { .. })));
+		assert!(matches!("USD".parse::<Money>(), Err(Error::Decode { .. })));
+
+		// unknown code
+		assert!(matches!("20.00 TMT".parse::<Money>(), Err(Error::UnsupportedCurrency(_))));
+		assert!(matches!("20.00TMT".parse::<Money>(), Err(Error::UnsupportedCurrency(_))));
+	}
+
+	#[test]
+	fn underscores()
+	{
+		let usd = Money::new(1000_50, 2, Currency::Usd);
+
+		assert_eq!("1_000.50 USD".parse::<Money>().unwrap(), usd);
+		assert_eq!("1_000.50USD".parse::<Money>().unwrap(), usd);
+		assert_eq!("$1_000.50".parse::<Money>().unwrap(), usd);
+		assert_eq!("-1_000.50 USD".parse::<Money>().unwrap(), -usd);
+		let jpy = Money::new(1_000_000, 0, Currency::Jpy);
+		assert_eq!("1_000_000 JPY".parse::<Money>().unwrap(), jpy);
+
+		// the code is not part of the amount
+		assert!(matches!("1_000.50 US_D".parse::<Money>(), Err(Error::Decode { .. })));
+	}
+}
@@ -1,6 +1,7 @@
 use core::ops::Mul;
 
 use super::Money;
+use crate::Decimal;
 
 impl Mul for Money
 {
@@ -37,3 +38,34 @@ impl Mul for Money
 		self.unchecked(Mul::mul, rhs)
 	}
 }
+
+impl Mul<Decimal> for Money
+{
+	type Output = Self;
+
+	/// Scale the amount of this [`Money`] by some factor (e.g. a quantity, or tax rate).
+	///
+	/// # Panics
+	///
+	/// * When [`Decimal::mul`] does.
+	///
+	/// # See also
+	///
+	/// * [`Mul::mul`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// assert_eq!(
+	///   Money::new(10, 0, Currency::Usd) * Decimal::new(3, 0),
+	///   Money::new(30, 0, Currency::Usd)
+	/// );
+	/// ```
+	fn mul(self, rhs: Decimal) -> Self::Output
+	{
+		Self { amount: self.amount * rhs, currency: self.currency }
+	}
+}
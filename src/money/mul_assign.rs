@@ -1,6 +1,7 @@
 use core::ops::{Mul, MulAssign};
 
 use super::Money;
+use crate::Decimal;
 
 impl MulAssign for Money
 {
@@ -35,3 +36,29 @@ impl MulAssign for Money
 		*self = self.mul(rhs);
 	}
 }
+
+impl MulAssign<Decimal> for Money
+{
+	/// # Panics
+	///
+	/// * When [`Money::mul`] does.
+	///
+	/// # See also
+	///
+	/// * [`MulAssign::mul_assign`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Decimal, Money};
+	///
+	/// let mut foo = Money::new(10, 0, Currency::Usd);
+	/// foo *= Decimal::new(3, 0);
+	/// assert_eq!(foo, Money::new(30, 0, Currency::Usd));
+	/// ```
+	fn mul_assign(&mut self, rhs: Decimal)
+	{
+		*self = self.mul(rhs);
+	}
+}
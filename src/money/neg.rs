@@ -0,0 +1,25 @@
+use core::ops::Neg;
+
+use super::Money;
+
+impl Neg for Money
+{
+	type Output = Self;
+
+	/// # See also
+	///
+	/// * [`Neg::neg`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Money};
+	///
+	/// assert_eq!(-Money::new(20_00, 2, Currency::Usd), Money::new(-20_00, 2, Currency::Usd));
+	/// ```
+	fn neg(self) -> Self::Output
+	{
+		Self { amount: -self.amount, currency: self.currency }
+	}
+}
@@ -11,3 +11,45 @@ impl TryFrom<&str> for Money
 		s.parse()
 	}
 }
+
+impl TryFrom<&String> for Money
+{
+	type Error = <Self as FromStr>::Err;
+
+	/// Same as converting from a [`&str`](str).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Money};
+	///
+	/// let s = String::from("20.00 USD");
+	/// assert_eq!(Money::try_from(&s).unwrap(), Money::new(20_00, 2, Currency::Usd));
+	/// ```
+	fn try_from(s: &String) -> Result<Self, Self::Error>
+	{
+		Self::try_from(s.as_str())
+	}
+}
+
+impl TryFrom<String> for Money
+{
+	type Error = <Self as FromStr>::Err;
+
+	/// Same as converting from a [`&str`](str).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use pretty_assertions::assert_eq;
+	/// use money2::{Currency, Money};
+	///
+	/// let money: Money = String::from("20.00 USD").try_into().unwrap();
+	/// assert_eq!(money, Money::new(20_00, 2, Currency::Usd));
+	/// ```
+	fn try_from(s: String) -> Result<Self, Self::Error>
+	{
+		Self::try_from(s.as_str())
+	}
+}
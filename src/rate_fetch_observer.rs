@@ -0,0 +1,25 @@
+use core::time::Duration;
+use std::path::Path;
+
+/// Observes [`ExchangeRates`](crate::ExchangeRates) being read from the cache or downloaded from
+/// the ECB (e.g. to record metrics, or trace how long downloads take). Every method does nothing
+/// by default.
+///
+/// # See also
+///
+/// * [`ExchangeRates::clear_observer`](crate::ExchangeRates::clear_observer)
+/// * [`ExchangeRates::set_observer`](crate::ExchangeRates::set_observer)
+#[allow(unused_variables, reason = "the default implementations do nothing")]
+pub trait RateFetchObserver: Send + Sync
+{
+	/// Called when exchange rates (e.g. for [`ExchangeRates::new`](crate::ExchangeRates::new), or
+	/// the historical record) are read from the cache at the `path` instead of being downloaded.
+	fn on_cache_hit(&self, path: &Path) {}
+
+	/// Called when a download of exchange rates has finished, with the number of `bytes` that were
+	/// downloaded, and the `duration` of the download.
+	fn on_download_complete(&self, bytes: usize, duration: Duration) {}
+
+	/// Called before exchange rates are downloaded from the `url`.
+	fn on_download_start(&self, url: &str) {}
+}
@@ -1,16 +1,146 @@
-use std::io::{Cursor, Read};
+use std::{
+	io::{Cursor, Read},
+	sync::{PoisonError, RwLock},
+};
+#[cfg(feature = "observer")]
+use std::time::Instant;
 
+use reqwest::Client;
 use zip::ZipArchive;
 
+#[cfg(feature = "observer")]
+use crate::RateFetchObserver;
 use crate::Result;
 
+/// The [`reqwest::blocking::Client`] which downloads exchange rates, if not the default.
+#[cfg(feature = "blocking")]
+pub static BLOCKING_CLIENT: RwLock<Option<reqwest::blocking::Client>> = RwLock::new(None);
+
+/// The [`Client`] which downloads exchange rates, if not the default.
+pub static CLIENT: RwLock<Option<Client>> = RwLock::new(None);
+
+/// The [`RateFetchObserver`] which is notified of downloads, if any.
+#[cfg(feature = "observer")]
+pub static OBSERVER: RwLock<Option<Box<dyn RateFetchObserver>>> = RwLock::new(None);
+
+/// Notify the [`OBSERVER`] (if there is one) of some event.
+#[cfg(feature = "observer")]
+pub fn observe<F>(f: F)
+where
+	F: FnOnce(&dyn RateFetchObserver),
+{
+	if let Some(observer) = OBSERVER.read().unwrap_or_else(PoisonError::into_inner).as_deref()
+	{
+		f(observer);
+	}
+}
+
+/// Get the [`BLOCKING_CLIENT`], or a default [`reqwest::blocking::Client`] if there is none.
+#[cfg(feature = "blocking")]
+fn blocking_client() -> Result<reqwest::blocking::Client>
+{
+	let client = BLOCKING_CLIENT.read().unwrap_or_else(PoisonError::into_inner).clone();
+	client.map_or_else(|| reqwest::blocking::Client::builder().build().map_err(Into::into), Ok)
+}
+
+/// Get the [`CLIENT`], or a default [`Client`] if there is none.
+pub fn client() -> Result<Client>
+{
+	let client = CLIENT.read().unwrap_or_else(PoisonError::into_inner).clone();
+	client.map_or_else(|| Client::builder().build().map_err(Into::into), Ok)
+}
+
 /// [`GET`](reqwest::get)s the [**zipped**](ZipArchive) the zipped file at the `url` and unzip it,
 /// returning the first file inside the zip.
 pub async fn get_unzipped(url: &str) -> Result<String>
 {
-	let response = reqwest::get(url).await?;
+	get_unzipped_with(&client()?, url).await
+}
+
+/// Like [`get_unzipped`], but uses the given `client` rather than the [`CLIENT`].
+pub async fn get_unzipped_with(client: &Client, url: &str) -> Result<String>
+{
+	#[cfg(feature = "observer")]
+	let start = observe_download_start(url);
+
+	let response = client.get(url).send().await?;
 	let bytes = response.bytes().await?;
 
+	#[cfg(feature = "observer")]
+	observe_download_complete(bytes.len(), start);
+	unzip(bytes)
+}
+
+/// Like [`get_unzipped`], but blocks the current thread instead of being `async`.
+///
+/// # Panics
+///
+/// * When called from within an `async` runtime, because [`reqwest::blocking`] does.
+#[cfg(feature = "blocking")]
+pub fn get_unzipped_blocking(url: &str) -> Result<String>
+{
+	#[cfg(feature = "observer")]
+	let start = observe_download_start(url);
+
+	let response = blocking_client()?.get(url).send()?;
+	let bytes = response.bytes()?;
+
+	#[cfg(feature = "observer")]
+	observe_download_complete(bytes.len(), start);
+	unzip(bytes)
+}
+
+/// [`GET`](reqwest::get)s the text of the file at the `url`.
+#[cfg(feature = "xml")]
+pub async fn get_text(url: &str) -> Result<String>
+{
+	#[cfg(feature = "observer")]
+	let start = observe_download_start(url);
+
+	let response = client()?.get(url).send().await?;
+	let text = response.text().await?;
+
+	#[cfg(feature = "observer")]
+	observe_download_complete(text.len(), start);
+	Ok(text)
+}
+
+/// Send a `HEAD` request to the `url`, returning [`Ok`] if the server responds with a success
+/// status. Nothing is downloaded, so the [`OBSERVER`] is not notified.
+pub async fn head(url: &str) -> Result<()>
+{
+	head_with(&client()?, url).await
+}
+
+/// Like [`head`], but uses the given `client` rather than the [`CLIENT`].
+async fn head_with(client: &Client, url: &str) -> Result<()>
+{
+	client.head(url).send().await?.error_for_status()?;
+	Ok(())
+}
+
+/// Notify the [`OBSERVER`] that a download of some number of `bytes`, which began at the `start`,
+/// is complete.
+#[cfg(feature = "observer")]
+fn observe_download_complete(bytes: usize, start: Instant)
+{
+	let duration = start.elapsed();
+	observe(|o| o.on_download_complete(bytes, duration));
+}
+
+/// Notify the [`OBSERVER`] that a download from the `url` is starting, returning when it started.
+#[cfg(feature = "observer")]
+fn observe_download_start(url: &str) -> Instant
+{
+	observe(|o| o.on_download_start(url));
+	Instant::now()
+}
+
+/// Unzip the `bytes` of a [`ZipArchive`], returning the first file inside the zip.
+fn unzip<B>(bytes: B) -> Result<String>
+where
+	B: AsRef<[u8]>,
+{
 	let mut archive = ZipArchive::new(Cursor::new(bytes))?;
 	let mut file = archive.by_index(0)?;
 
@@ -21,3 +151,101 @@ pub async fn get_unzipped(url: &str) -> Result<String>
 
 	Ok(contents)
 }
+
+/// Create a [`Client`] which sends every request through a mock proxy. The proxy accepts a single
+/// connection, writes the raw `response` to it, and then joins with the request line it received.
+///
+/// NOTE: an `https` URL is tunneled through the proxy with `CONNECT`, so only an `http` URL can
+///       be given a successful `response`.
+#[cfg(test)]
+pub fn mock_proxy(response: Vec<u8>) -> (Client, std::thread::JoinHandle<String>)
+{
+	use std::{
+		io::{BufRead, BufReader, Write},
+		net::TcpListener,
+		thread,
+	};
+
+	use reqwest::Proxy;
+
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let address = listener.local_addr().unwrap();
+	let proxy = thread::spawn(move || {
+		let (mut stream, _) = listener.accept().unwrap();
+		let mut request_line = String::new();
+		BufReader::new(&stream).read_line(&mut request_line).unwrap();
+
+		stream.write_all(&response).unwrap();
+		request_line
+	});
+
+	let client = Client::builder().proxy(Proxy::all(format!("http://{address}")).unwrap()).build();
+	(client.unwrap(), proxy)
+}
+
+#[cfg(test)]
+mod tests
+{
+	use std::{
+		io::{BufRead, BufReader, Cursor, Write},
+		net::TcpListener,
+		thread,
+	};
+
+	use pretty_assertions::assert_eq;
+	use reqwest::{Client, Proxy};
+	use zip::{write::FileOptions, ZipWriter};
+
+	use crate::SAMPLE_EXCHANGE_RATES_CSV;
+
+	#[tokio::test]
+	async fn get_unzipped_with()
+	{
+		let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+		zip.start_file("eurofxref.csv", FileOptions::default()).unwrap();
+		zip.write_all(SAMPLE_EXCHANGE_RATES_CSV.as_bytes()).unwrap();
+		let zipped = zip.finish().unwrap().into_inner();
+
+		let headers = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n", zipped.len());
+		let (client, proxy) = super::mock_proxy([headers.into_bytes(), zipped].concat());
+
+		// NOTE: `money2.invalid` cannot resolve, so the download only succeeds through the proxy
+		let csv = super::get_unzipped_with(&client, "http://money2.invalid/eurofxref.zip")
+			.await
+			.unwrap();
+
+		assert_eq!(csv, SAMPLE_EXCHANGE_RATES_CSV);
+		assert!(proxy.join().unwrap().starts_with("GET http://money2.invalid/eurofxref.zip "));
+	}
+
+	#[tokio::test]
+	async fn head_with()
+	{
+		// NOTE: the mock responds to each request with the given status line, and then returns
+		//       the request lines that it received.
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let address = listener.local_addr().unwrap();
+		let proxy = thread::spawn(move || {
+			["200 OK", "404 Not Found"].map(|status| {
+				let (mut stream, _) = listener.accept().unwrap();
+				let mut request_line = String::new();
+				BufReader::new(&stream).read_line(&mut request_line).unwrap();
+
+				write!(stream, "HTTP/1.1 {status}\r\nContent-Length: 0\r\n\r\n").unwrap();
+				request_line
+			})
+		});
+
+		// NOTE: `money2.invalid` cannot resolve, so the request only succeeds through the proxy
+		let client = Client::builder().proxy(Proxy::all(format!("http://{address}")).unwrap()).build();
+		let client = client.unwrap();
+		let url = "http://money2.invalid/eurofxref.zip";
+
+		assert!(super::head_with(&client, url).await.is_ok());
+		assert!(super::head_with(&client, url).await.is_err());
+
+		let [ok, not_found] = proxy.join().unwrap();
+		assert!(ok.starts_with("HEAD http://money2.invalid/eurofxref.zip "));
+		assert!(not_found.starts_with("HEAD http://money2.invalid/eurofxref.zip "));
+	}
+}
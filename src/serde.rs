@@ -0,0 +1,132 @@
+//! Functions for use with serde's field attributes, for when the [`Deserialize`] implementation of
+//! a type does not accept every form it may be serialized in.
+
+use core::fmt::{Formatter, Result as FmtResult};
+
+use serde::{
+	de::{
+		value::{MapAccessDeserializer, SeqAccessDeserializer},
+		Error,
+		MapAccess,
+		SeqAccess,
+		Visitor,
+	},
+	Deserialize,
+	Deserializer,
+};
+
+use crate::Money;
+
+/// A [`Visitor`] which accepts either the fields of [`Money`], or a string which can be
+/// [parsed](core::str::FromStr) into [`Money`].
+struct MoneyVisitor;
+
+impl<'de> Visitor<'de> for MoneyVisitor
+{
+	type Value = Money;
+
+	fn expecting(&self, f: &mut Formatter) -> FmtResult
+	{
+		f.write_str(r#"an amount and currency, or a string such as "20.00 USD""#)
+	}
+
+	fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+	where
+		A: MapAccess<'de>,
+	{
+		Money::deserialize(MapAccessDeserializer::new(map))
+	}
+
+	fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+	where
+		A: SeqAccess<'de>,
+	{
+		Money::deserialize(SeqAccessDeserializer::new(seq))
+	}
+
+	fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+	where
+		E: Error,
+	{
+		s.parse().map_err(E::custom)
+	}
+}
+
+/// Deserialize [`Money`] from either its fields (e.g. `{"amount": "20.00", "currency": "USD"}`)
+/// or a string which can be [parsed](core::str::FromStr) into [`Money`] (e.g. `"20.00 USD"`).
+/// This allows [`Money`] to be read from services which serialize it differently.
+///
+/// Since this relies on [`Deserializer::deserialize_any`], it only works with self-describing
+/// formats (e.g. JSON).
+///
+/// # Examples
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// use money2::{Currency, Money};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Invoice
+/// {
+///   #[serde(deserialize_with = "money2::serde::either")]
+///   total: Money,
+/// }
+///
+/// let money = Money::new(20_00, 2, Currency::Usd);
+///
+/// let invoice: Invoice =
+///   serde_json::from_str(r#"{"total": {"amount": "20.00", "currency": "USD"}}"#).unwrap();
+/// assert_eq!(invoice.total, money);
+///
+/// let invoice: Invoice = serde_json::from_str(r#"{"total": "20.00 USD"}"#).unwrap();
+/// assert_eq!(invoice.total, money);
+/// ```
+///
+/// # Errors
+///
+/// * When the value is neither the fields of [`Money`] nor a string which can be parsed into
+///   [`Money`].
+pub fn either<'de, D>(deserializer: D) -> Result<Money, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	deserializer.deserialize_any(MoneyVisitor)
+}
+
+#[cfg(test)]
+mod tests
+{
+	use pretty_assertions::assert_eq;
+	use serde::Deserialize;
+
+	use crate::{Currency, Money};
+
+	#[derive(Debug, Deserialize, PartialEq)]
+	#[serde(transparent)]
+	struct Either(#[serde(deserialize_with = "super::either")] Money);
+
+	#[test]
+	fn either()
+	{
+		let money = Either(Money::new(20_00, 2, Currency::Usd));
+
+		let from_fields: Either =
+			serde_json::from_str(r#"{"amount": "20.00", "currency": "USD"}"#).unwrap();
+		let from_str: Either = serde_json::from_str(r#""20.00 USD""#).unwrap();
+		let from_seq: Either = serde_json::from_str(r#"["20.00", "USD"]"#).unwrap();
+
+		assert_eq!(from_fields, money);
+		assert_eq!(from_str, money);
+		assert_eq!(from_seq, money);
+		let round_trip = serde_json::to_string(&money.0).unwrap();
+		assert_eq!(serde_json::from_str::<Either>(&round_trip).unwrap(), money);
+
+		assert!(serde_json::from_str::<Either>(r#""twenty dollars""#).is_err());
+		assert!(serde_json::from_str::<Either>(r#"{"amount": "20.00"}"#).is_err());
+		assert!(serde_json::from_str::<Either>("20").is_err());
+
+		// the derived implementation is unchanged
+		assert!(serde_json::from_str::<Money>(r#""20.00 USD""#).is_err());
+	}
+}